@@ -0,0 +1,148 @@
+//! Generates the modules of library solutions and the registry of solutions that the main binary runs in-process.
+//! Every `src/solutions/day<day>.rs` file is included as a module of `advent_of_code::solutions`.
+//! Every `src/bin/<day>.rs` file without a library module is included as a module of the main binary.
+//! Both are collected into `SOLUTIONS`.
+//! Every `src/archive/<year>/day<day>.rs` file is included as a module of `advent_of_code::archive::y<year>`,
+//! and each archived year is collected into `ARCHIVES`.
+use std::{env, fs, path::Path};
+
+/// Lists the days of files in `dir` that are named `<prefix><day>.rs`, sorted.
+fn find_days(dir: &Path, prefix: &str) -> Vec<String> {
+    let mut days: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let file_name = entry.file_name().into_string().ok()?;
+                    let day = file_name.strip_prefix(prefix)?.strip_suffix(".rs")?;
+                    let is_day = day.len() == 2 && day.chars().all(|c| c.is_ascii_digit());
+                    is_day.then(|| day.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort_unstable();
+    days
+}
+
+/// Lists the years in `dir` that have a folder named like `2024`, sorted.
+fn find_years(dir: &Path) -> Vec<String> {
+    let mut years: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| {
+                    let year = entry.file_name().into_string().ok()?;
+                    let is_year = year.len() == 4 && year.chars().all(|c| c.is_ascii_digit());
+                    is_year.then_some(year)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    years.sort_unstable();
+    years
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+    println!("cargo:rerun-if-changed=src/solutions");
+    println!("cargo:rerun-if-changed=src/archive");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+    let lib_dir = Path::new(&manifest_dir).join("src").join("solutions");
+    let archive_dir = Path::new(&manifest_dir).join("src").join("archive");
+
+    let lib_days = find_days(&lib_dir, "day");
+    let bin_days: Vec<String> = find_days(&bin_dir, "")
+        .into_iter()
+        .filter(|day| !lib_days.contains(day))
+        .collect();
+
+    let mut lib_modules = String::new();
+
+    // NOTE: solutions are written against the clock, their style is up to whoever solves the
+    // puzzle. Clippy still checks them for correctness.
+    for day in &lib_days {
+        let path = lib_dir.join(format!("day{day}.rs"));
+        lib_modules.push_str(&format!(
+            "#[allow(dead_code, clippy::style, clippy::complexity)]\n#[path = {path:?}]\npub mod day{day};\n\n"
+        ));
+    }
+
+    let mut registry = String::new();
+
+    // NOTE: solution tests are already run as part of their binaries, skip them here.
+    for day in &bin_days {
+        let path = bin_dir.join(format!("{day}.rs"));
+        registry.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(dead_code)]\n#[path = {path:?}]\nmod day{day};\n\n"
+        ));
+    }
+
+    let mut entries: Vec<(&String, String)> = lib_days
+        .iter()
+        .map(|day| {
+            (
+                day,
+                format!("advent_of_code::solutions::day{day}::SOLUTION"),
+            )
+        })
+        .chain(
+            bin_days
+                .iter()
+                .map(|day| (day, format!("day{day}::SOLUTION"))),
+        )
+        .collect();
+
+    entries.sort_unstable();
+
+    let entries: Vec<String> = entries.into_iter().map(|(_, entry)| entry).collect();
+
+    registry.push_str(&format!(
+        "#[cfg(not(test))]\npub static SOLUTIONS: &advent_of_code::template::Registry = &[{}];\n\n",
+        entries.join(", ")
+    ));
+    registry.push_str(
+        "#[cfg(test)]\npub static SOLUTIONS: &advent_of_code::template::Registry = &[];\n",
+    );
+
+    let mut archive_modules = String::new();
+    let mut archives: Vec<String> = vec![];
+
+    for year in find_years(&archive_dir) {
+        let year_dir = archive_dir.join(&year);
+        let days = find_days(&year_dir, "day");
+
+        archive_modules.push_str(&format!("pub mod y{year} {{\n"));
+        for day in &days {
+            let path = year_dir.join(format!("day{day}.rs"));
+            archive_modules.push_str(&format!(
+                "    #[allow(dead_code, clippy::style, clippy::complexity)]\n    #[path = {path:?}]\n    pub mod day{day};\n"
+            ));
+        }
+        archive_modules.push_str("}\n\n");
+
+        let entries: Vec<String> = days
+            .iter()
+            .map(|day| format!("advent_of_code::archive::y{year}::day{day}::SOLUTION"))
+            .collect();
+        archives.push(format!("({year}, &[{}])", entries.join(", ")));
+    }
+
+    registry.push_str(&format!(
+        "\n#[cfg(not(test))]\npub static ARCHIVES: &[(u16, &advent_of_code::template::Registry)] = &[{}];\n\n",
+        archives.join(", ")
+    ));
+    registry.push_str(
+        "#[cfg(test)]\npub static ARCHIVES: &[(u16, &advent_of_code::template::Registry)] = &[];\n",
+    );
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("lib_solutions.rs"), lib_modules).unwrap();
+    fs::write(Path::new(&out_dir).join("lib_archive.rs"), archive_modules).unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
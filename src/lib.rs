@@ -1,3 +1,18 @@
+// NOTE: allows solutions in `src/solutions` to use the same paths as binaries, e.g. `advent_of_code::solution!`.
+extern crate self as advent_of_code;
+
+/// Solutions that live in the library, generated from the `src/solutions/day<day>.rs` files.
+/// Their parts and helpers can be used by other days, tools and integration tests, e.g. `solutions::day05::part_one`.
+pub mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/lib_solutions.rs"));
+}
+
+/// Solutions of past years, generated from the `src/archive/<year>/day<day>.rs` files that `cargo archive` creates.
+/// Each year is a module of its own, e.g. `archive::y2024::day05::part_one`.
+pub mod archive {
+    include!(concat!(env!("OUT_DIR"), "/lib_archive.rs"));
+}
+
 pub mod template;
 
 pub mod rect {
@@ -1,81 +1,228 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, archive, batch, download, example, leaderboard, read, run, scaffold, serve, solve, time,
+    verify,
+};
+use advent_of_code::template::{year, Registry};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
+
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::solve::AllocProfiler;
+    use advent_of_code::template::runner::{RunnerConfig, Verbosity};
+    use advent_of_code::template::{
+        all_days, bench::BenchConfig, input::InputSource, Day, DayRange, Part,
+    };
     use std::process;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            days: DayRange,
         },
         Read {
             day: Day,
         },
         Scaffold {
-            day: Day,
+            days: DayRange,
             download: bool,
             overwrite: bool,
         },
         Solve {
             day: Day,
             release: bool,
-            dhat: bool,
-            submit: Option<u8>,
+            profiler: AllocProfiler,
+            json: bool,
+            verbosity: Verbosity,
+            input: InputSource,
+            submit: Option<Part>,
+            watch: bool,
+            test: bool,
         },
         All {
-            release: bool,
+            config: RunnerConfig,
+        },
+        Run {
+            days: Vec<Day>,
+            config: RunnerConfig,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            compare_threshold: Option<f64>,
+            memory: bool,
+            config: RunnerConfig,
+        },
+        Example {
+            days: Vec<Day>,
+            config: RunnerConfig,
+        },
+        Verify {
+            days: Vec<Day>,
+            store: bool,
+            config: RunnerConfig,
+        },
+        Serve {
+            host: String,
+            port: u16,
+            config: RunnerConfig,
+        },
+        Leaderboard {
+            file: String,
+            day: Option<Day>,
+        },
+        Archive {
+            year: Option<u16>,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
+    }
+
+    impl AppArguments {
+        /// Whether the command runs solutions in-process, so it can run an archived year's.
+        fn supports_year(&self) -> bool {
+            matches!(
+                self,
+                Self::All { .. }
+                    | Self::Run { .. }
+                    | Self::Time { .. }
+                    | Self::Example { .. }
+                    | Self::Verify { .. }
+                    | Self::Serve { .. }
+            )
+        }
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parses the command and the archived year it should work on, if any.
+    pub fn parse() -> Result<(Option<u16>, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
+        let mut year: Option<u16> = args.opt_value_from_str("--year")?;
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+            Some("all") => {
+                // NOTE: the `all` alias always builds with `--release`, the flag is kept for compatibility.
+                args.contains("--release");
+                AppArguments::All {
+                    config: RunnerConfig::from_args(&mut args)?,
+                }
+            }
+            Some("run") => {
+                let config = RunnerConfig::from_args(&mut args)?;
+
+                let mut days = vec![];
+                while let Some(day) = args.opt_free_from_str()? {
+                    days.push(day);
+                }
+
+                AppArguments::Run { days, config }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let memory = args.contains("--memory");
+                let bench = BenchConfig::from_args(&mut args)?;
+                let compare = args.contains("--compare");
+                let threshold: f64 = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+                // NOTE: timing runs stay serial by default to avoid noisy measurements.
+                let config = RunnerConfig {
+                    bench: Some(bench),
+                    ..RunnerConfig::from_args(&mut args)?
+                };
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    compare_threshold: compare.then_some(threshold / 100.0),
+                    memory,
+                    config,
                 }
             }
+            Some("verify") => {
+                let store = args.contains("--store");
+                let config = RunnerConfig::from_args(&mut args)?;
+
+                let mut days = vec![];
+                while let Some(day) = args.opt_free_from_str()? {
+                    days.push(day);
+                }
+
+                AppArguments::Verify {
+                    days,
+                    store,
+                    config,
+                }
+            }
+            Some("serve") => AppArguments::Serve {
+                // NOTE: only reachable from this machine unless another host is passed explicitly.
+                host: args
+                    .opt_value_from_str("--host")?
+                    .unwrap_or_else(|| "127.0.0.1".into()),
+                port: args.opt_value_from_str("--port")?.unwrap_or(8080),
+                config: RunnerConfig::from_args(&mut args)?,
+            },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                day: args.opt_value_from_str("--day")?,
+                file: args.free_from_str()?,
+            },
+            Some("archive") => AppArguments::Archive { year: year.take() },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                days: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                days: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
+            Some("solve") if args.contains("--example") => {
+                let days = if args.contains("--all") {
+                    all_days().collect()
+                } else {
+                    vec![args.free_from_str()?]
+                };
+
+                AppArguments::Example {
+                    days,
+                    config: RunnerConfig::from_args(&mut args)?,
+                }
+            }
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                profiler: if args.contains("--dhat") {
+                    AllocProfiler::Dhat
+                } else if args.contains("--allocs") {
+                    AllocProfiler::Count
+                } else {
+                    AllocProfiler::None
+                },
+                json: args.contains("--json"),
+                verbosity: if args.contains("--quiet") {
+                    Verbosity::Quiet
+                } else if args.contains("--verbose") {
+                    Verbosity::Verbose
+                } else {
+                    Verbosity::Normal
+                },
+                input: InputSource::from_args(&mut args)?,
+                watch: args.contains("--watch"),
+                test: args.contains("--test"),
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -86,12 +233,38 @@ mod args {
             }
         };
 
+        if year.is_some() && !app_args.supports_year() {
+            eprintln!("`--year` is only supported by the all, run, time, solve --example, verify and serve commands.");
+            process::exit(1);
+        }
+
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
+    }
+}
+
+/// The solutions of the archived `year`, or of the current year if there is none.
+fn select_solutions(year: Option<u16>) -> &'static Registry {
+    let Some(year) = year else {
+        return solutions::SOLUTIONS;
+    };
+
+    match solutions::ARCHIVES
+        .iter()
+        .find(|(archived, _)| *archived == year)
+    {
+        Some((_, solutions)) => {
+            year::select(Some(year));
+            solutions
+        }
+        None => {
+            eprintln!("{year} was not archived, see `cargo archive`.");
+            std::process::exit(1);
+        }
     }
 }
 
@@ -101,44 +274,75 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
+        Ok((year, args)) => match args {
+            AppArguments::All { config } => all::handle(select_solutions(year), &config),
+            AppArguments::Run { days, config } => {
+                run::handle(select_solutions(year), &days, &config);
+            }
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                compare_threshold,
+                memory,
+                config,
+            } => {
+                time::handle(
+                    select_solutions(year),
+                    day,
+                    all,
+                    store,
+                    compare_threshold,
+                    memory,
+                    &config,
+                );
+            }
+            AppArguments::Example { days, config } => {
+                example::handle(select_solutions(year), &days, &config);
+            }
+            AppArguments::Verify {
+                days,
+                store,
+                config,
+            } => verify::handle(select_solutions(year), &days, store, &config),
+            AppArguments::Serve { host, port, config } => {
+                serve::handle(select_solutions(year), &host, port, &config);
+            }
+            AppArguments::Leaderboard { file, day } => leaderboard::handle(&file, day),
+            AppArguments::Archive { year } => archive::handle(year),
+            AppArguments::Download { days } => match days.single() {
+                Some(day) => download::handle(day),
+                None => batch::handle(&days.days().collect::<Vec<_>>(), false, true, false),
+            },
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
-                day,
+                days,
                 download,
                 overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+            } => match days.single() {
+                Some(day) => {
+                    scaffold::handle(day, overwrite);
+                    if download {
+                        download::handle(day);
+                    }
                 }
-            }
+                None => batch::handle(&days.days().collect::<Vec<_>>(), true, download, overwrite),
+            },
             AppArguments::Solve {
                 day,
                 release,
-                dhat,
+                profiler,
+                json,
+                verbosity,
+                input,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                watch,
+                test,
+            } => solve::handle(
+                day, release, profiler, json, verbosity, &input, submit, watch, test,
+            ),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
@@ -0,0 +1,176 @@
+/// A lightweight global allocator that counts allocations, enabled with the `count-allocs` feature.
+///
+/// Counters are kept per thread, so parts that run concurrently (e.g. `cargo all --jobs 4` or
+/// `cargo test`) don't skew each other's numbers.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// Allocation statistics of a measured closure.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub count: u64,
+    /// Total number of allocated bytes.
+    pub bytes: u64,
+    /// Maximum number of bytes that were live at the same time.
+    pub peak_bytes: u64,
+}
+
+/// Wraps the system allocator and records allocations of the current thread.
+pub struct CountingAlloc;
+
+struct Counters {
+    count: Cell<u64>,
+    bytes: Cell<u64>,
+    /// NOTE: memory can be freed on a different thread than it was allocated on, so this can go negative.
+    live: Cell<i64>,
+    peak: Cell<i64>,
+}
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            count: Cell::new(0),
+            bytes: Cell::new(0),
+            live: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    };
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn record_alloc(size: usize) {
+    let _ = COUNTERS.try_with(|c| {
+        c.count.set(c.count.get() + 1);
+        c.bytes.set(c.bytes.get() + size as u64);
+        c.live.set(c.live.get() + size as i64);
+        c.peak.set(c.peak.get().max(c.live.get()));
+    });
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn record_dealloc(size: usize) {
+    let _ = COUNTERS.try_with(|c| c.live.set(c.live.get() - size as i64));
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_alloc(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_alloc(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record_dealloc(layout.size());
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_dealloc(layout.size());
+        record_alloc(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Whether the counting allocator is installed as the global allocator.
+pub const fn is_enabled() -> bool {
+    cfg!(all(feature = "count-allocs", not(feature = "dhat-heap")))
+}
+
+/// Runs `func` and records its allocations on the current thread.
+/// Returns `None` for the statistics if the counting allocator is not enabled.
+#[allow(clippy::cast_sign_loss)]
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !is_enabled() {
+        return (func(), None);
+    }
+
+    let (count, bytes, live) = COUNTERS.with(|c| {
+        // start tracking the peak from the currently live bytes.
+        c.peak.set(c.live.get());
+        (c.count.get(), c.bytes.get(), c.live.get())
+    });
+
+    let result = func();
+
+    let stats = COUNTERS.with(|c| AllocStats {
+        count: c.count.get() - count,
+        bytes: c.bytes.get() - bytes,
+        peak_bytes: (c.peak.get() - live).max(0) as u64,
+    });
+
+    (result, Some(stats))
+}
+
+/// Test helper that runs `func` and panics if it allocates more than `max_bytes` in total.
+/// If the counting allocator is not enabled, the budget is not checked.
+///
+/// ```ignore
+/// let result = assert_alloc_budget(64 * 1024, || part_one(&input));
+/// ```
+pub fn assert_alloc_budget<T>(max_bytes: u64, func: impl FnOnce() -> T) -> T {
+    let (result, stats) = measure(func);
+
+    match stats {
+        Some(stats) => assert!(
+            stats.bytes <= max_bytes,
+            "allocated {} bytes in {} allocations, exceeding the budget of {max_bytes} bytes",
+            stats.bytes,
+            stats.count
+        ),
+        None => eprintln!(
+            "allocation budget not checked, run with `--features count-allocs` to enable it."
+        ),
+    }
+
+    result
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(
+    feature = "test_lib",
+    feature = "count-allocs",
+    not(feature = "dhat-heap")
+))]
+mod tests {
+    use std::hint::black_box;
+
+    use super::{assert_alloc_budget, measure};
+
+    #[test]
+    fn counts_allocations() {
+        let (_, stats) = measure(|| {
+            let a = black_box(vec![0_u8; 100]);
+            let b = black_box(vec![0_u8; 50]);
+            drop(a);
+            drop(b);
+            black_box(vec![0_u8; 10]);
+        });
+
+        let stats = stats.unwrap();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.bytes, 160);
+        assert_eq!(stats.peak_bytes, 150);
+    }
+
+    #[test]
+    fn passes_within_budget() {
+        assert_eq!(
+            assert_alloc_budget(100, || black_box(vec![1_u8; 100]).len()),
+            100
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_over_budget() {
+        assert_alloc_budget(100, || black_box(vec![1_u8; 101]).len());
+    }
+}
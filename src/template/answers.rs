@@ -0,0 +1,237 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::input::InputSource;
+use crate::template::runner::PartResult;
+use crate::template::{year, Day, Part};
+
+/// Represents the accepted answers of a single day, or the expected answers of its example.
+/// Can be serialized from / to JSON, one file per day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// The outcome of checking a part result against the accepted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verification {
+    /// The part produced the accepted answer.
+    Match,
+    /// The part produced a different answer, or none at all.
+    Mismatch {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// No answer has been accepted for the part yet.
+    Unknown,
+}
+
+impl Answers {
+    /// Dehydrate answers to the JSON file of `day`.
+    pub fn store_file(&self, day: Day) -> Result<(), Error> {
+        fs::create_dir_all(year::data_dir().join("answers"))?;
+        self.store_path(&Self::file_path(day))
+    }
+
+    /// Rehydrate the answers of `day` from its JSON file. If not present, returns empty answers.
+    pub fn read_from_file(day: Day) -> Self {
+        Self::read_path(&Self::file_path(day))
+    }
+
+    /// Dehydrate expected answers to the sidecar file of the example of `day`.
+    pub fn store_example_file(&self, day: Day) -> Result<(), Error> {
+        self.store_path(&Self::example_file_path(day))
+    }
+
+    /// Rehydrate the expected answers of the example of `day` from its sidecar file, e.g. `data/examples/01.answers.json`.
+    /// If not present, returns empty answers.
+    pub fn read_example_file(day: Day) -> Self {
+        Self::read_path(&Self::example_file_path(day))
+    }
+
+    /// Rehydrate the answers that are expected for `day` when reading its input from `source`:
+    /// the accepted answers for real inputs, and the expected ones for examples.
+    /// Other inputs have no expected answers.
+    pub fn read_for_input(day: Day, source: &InputSource) -> Self {
+        match source {
+            source if *source == InputSource::default() => Self::read_from_file(day),
+            InputSource::Set(set) if set == "examples" => Self::read_example_file(day),
+            _ => Self::default(),
+        }
+    }
+
+    /// Records the answer of a part as accepted, storing it immediately.
+    pub fn record(day: Day, part: Part, answer: &str) -> Result<(), Error> {
+        let mut answers = Self::read_from_file(day);
+        answers.set(part, answer.into());
+        answers.store_file(day)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part_1 = Some(answer),
+            Part::Two => self.part_2 = Some(answer),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part_1.is_none() && self.part_2.is_none()
+    }
+
+    /// Check the answer of a part result against the accepted one.
+    pub fn verify(&self, result: &PartResult) -> Verification {
+        let actual = result.answer.as_ref().map(ToString::to_string);
+
+        match self.get(result.part) {
+            None => Verification::Unknown,
+            Some(expected) if actual.as_deref() == Some(expected) => Verification::Match,
+            Some(expected) => Verification::Mismatch {
+                expected: expected.into(),
+                actual,
+            },
+        }
+    }
+
+    fn store_path(&self, path: &Path) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    fn read_path(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    fn file_path(day: Day) -> PathBuf {
+        year::data_dir().join("answers").join(format!("{day}.json"))
+    }
+
+    fn example_file_path(day: Day) -> PathBuf {
+        year::data_dir()
+            .join("examples")
+            .join(format!("{day}.answers.json"))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let to_json = |answer: &Option<String>| match answer {
+            Some(x) => JsonValue::String(x.clone()),
+            None => JsonValue::Null,
+        };
+
+        map.insert("part_1".into(), to_json(&value.part_1));
+        map.insert("part_2".into(), to_json(&value.part_2));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let answer = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => v
+                .get::<String>()
+                .cloned()
+                .map(Some)
+                .ok_or(format!("expected `json.{key}` to be null or string.")),
+        };
+
+        Ok(Answers {
+            part_1: answer("part_1")?,
+            part_2: answer("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Answers, Verification};
+    use crate::template::runner::PartResult;
+    use crate::template::Part;
+
+    #[test]
+    fn roundtrips_json() {
+        let answers = Answers {
+            part_1: Some("11".into()),
+            part_2: Some("##.\n.##".into()),
+        };
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn handles_missing_parts() {
+        let answers = Answers::try_from(r#"{ "part_1": "11", "part_2": null }"#.to_string());
+        assert_eq!(answers.unwrap().get(Part::Two), None);
+
+        let answers = Answers::try_from(r#"{ "part_1": 11 }"#.to_string());
+        assert_eq!(answers.is_err(), true);
+    }
+
+    #[test]
+    fn verifies_part_results() {
+        let mut answers = Answers::default();
+        answers.set(Part::One, "11".into());
+
+        assert_eq!(
+            answers.verify(&PartResult::mock(Part::One, Some("11"), Duration::ZERO)),
+            Verification::Match
+        );
+        assert_eq!(
+            answers.verify(&PartResult::mock(Part::One, Some("12"), Duration::ZERO)),
+            Verification::Mismatch {
+                expected: "11".into(),
+                actual: Some("12".into())
+            }
+        );
+        assert_eq!(
+            answers.verify(&PartResult::mock(Part::One, None, Duration::ZERO)),
+            Verification::Mismatch {
+                expected: "11".into(),
+                actual: None
+            }
+        );
+        assert_eq!(
+            answers.verify(&PartResult::mock(Part::Two, Some("31"), Duration::ZERO)),
+            Verification::Unknown
+        );
+    }
+}
@@ -4,7 +4,7 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{Day, Part};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -72,12 +72,24 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+pub fn submit(day: Day, part: Part, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    // capture stdout, so the response can be checked by `is_accepted`.
+    // it's echoed to stderr, stdout is reserved for the results of the runner.
+    let output = call_aoc_cli_with(&args, Stdio::piped());
+    if let Ok(output) | Err(AocCommandError::BadExitStatus(output)) = &output {
+        eprint!("{}", String::from_utf8_lossy(&output.stdout));
+    }
+    output
+}
+
+/// Whether the output of `submit` reports the answer as correct.
+pub fn is_accepted(output: &Output) -> bool {
+    String::from_utf8_lossy(&output.stdout).contains("That's the right answer")
 }
 
 fn get_input_path(day: Day) -> String {
@@ -88,7 +100,7 @@ fn get_puzzle_path(day: Day) -> String {
     format!("data/puzzles/{day}.md")
 }
 
-fn get_year() -> Option<u16> {
+pub(crate) fn get_year() -> Option<u16> {
     match std::env::var("AOC_YEAR") {
         Ok(x) => x.parse().ok().or(None),
         Err(_) => None,
@@ -109,10 +121,14 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
 }
 
 fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+    call_aoc_cli_with(args, Stdio::inherit())
+}
+
+fn call_aoc_cli_with(args: &[String], stdout: Stdio) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(stdout)
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
@@ -0,0 +1,250 @@
+/// Settings and statistics of solution benchmarks.
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+/// The minimum number of samples taken per benchmark, regardless of the target duration.
+const MIN_SAMPLES: u128 = 10;
+
+/// The statistic of a benchmark that is reported as its headline duration,
+/// e.g. in `data/timings.json` and the readme.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Statistic {
+    #[default]
+    Mean,
+    Median,
+    Min,
+    P95,
+}
+
+/// Controls how a solution part is benchmarked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Time spent running the solution before samples are recorded.
+    pub warmup: Duration,
+    /// Time the benchmark aims to spend on recording samples.
+    pub target: Duration,
+    /// Upper bound for the number of recorded samples.
+    pub max_samples: u128,
+    /// The statistic reported as the part's duration.
+    pub statistic: Statistic,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(100),
+            target: Duration::from_secs(1),
+            max_samples: 10_000,
+            statistic: Statistic::default(),
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads benchmark settings from command-line arguments, falling back to defaults:
+    /// `--warmup <ms>`, `--bench-time <ms>`, `--max-samples <n>` and `--stat <statistic>`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let defaults = Self::default();
+
+        Ok(Self {
+            warmup: args
+                .opt_value_from_str("--warmup")?
+                .map_or(defaults.warmup, Duration::from_millis),
+            target: args
+                .opt_value_from_str("--bench-time")?
+                .map_or(defaults.target, Duration::from_millis),
+            max_samples: args
+                .opt_value_from_str("--max-samples")?
+                .unwrap_or(defaults.max_samples),
+            statistic: args
+                .opt_value_from_str("--stat")?
+                .unwrap_or(defaults.statistic),
+        })
+    }
+
+    /// Whether enough samples have been recorded after `elapsed` time.
+    pub fn is_done(&self, samples: u128, elapsed: Duration) -> bool {
+        samples >= self.max_samples.max(1) || (samples >= MIN_SAMPLES && elapsed >= self.target)
+    }
+}
+
+/// Summary statistics over the samples of a benchmark.
+/// Mean and standard deviation are computed after discarding outliers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    /// Number of samples outside of the Tukey fences (1.5 × IQR).
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics over a set of samples. Returns `None` if there are no samples.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let fence = 1.5 * (q3 - q1);
+
+        let inliers: Vec<f64> = nanos
+            .iter()
+            .copied()
+            .filter(|x| *x >= q1 - fence && *x <= q3 + fence)
+            .collect();
+
+        let mean = inliers.iter().sum::<f64>() / inliers.len() as f64;
+        let variance =
+            inliers.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / inliers.len() as f64;
+
+        let to_duration = |x: f64| Duration::from_nanos(x.round() as u64);
+
+        Some(Self {
+            mean: to_duration(mean),
+            median: to_duration(percentile(&nanos, 0.5)),
+            min: to_duration(nanos[0]),
+            max: to_duration(nanos[nanos.len() - 1]),
+            p95: to_duration(percentile(&nanos, 0.95)),
+            stddev: to_duration(variance.sqrt()),
+            outliers: (nanos.len() - inliers.len()) as u128,
+        })
+    }
+
+    /// Returns the value of the given statistic.
+    pub fn get(&self, statistic: Statistic) -> Duration {
+        match statistic {
+            Statistic::Mean => self.mean,
+            Statistic::Median => self.median,
+            Statistic::Min => self.min,
+            Statistic::P95 => self.p95,
+        }
+    }
+}
+
+/// Linearly interpolated percentile of sorted values, `p` being in range 0 to 1.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Statistic {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Statistic::Mean => "mean",
+            Statistic::Median => "median",
+            Statistic::Min => "min",
+            Statistic::P95 => "p95",
+        }
+    }
+}
+
+impl Display for Statistic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Statistic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mean" => Ok(Statistic::Mean),
+            "median" => Ok(Statistic::Median),
+            "min" => Ok(Statistic::Min),
+            "p95" => Ok(Statistic::P95),
+            x => Err(format!(
+                "unknown statistic `{x}`, expecting one of: mean, median, min, p95"
+            )),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{BenchConfig, BenchStats, Statistic};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 20, 30, 40, 50])).unwrap();
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.p95, Duration::from_nanos(48));
+        assert_eq!(stats.stddev, Duration::from_nanos(14));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats =
+            BenchStats::from_samples(&nanos(&[100, 100, 101, 100, 100, 100, 10_000])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(100));
+        assert_eq!(stats.max, Duration::from_nanos(10_000));
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn selects_statistic() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 20, 60])).unwrap();
+        assert_eq!(stats.get(Statistic::Mean), Duration::from_nanos(30));
+        assert_eq!(stats.get(Statistic::Median), Duration::from_nanos(20));
+        assert_eq!(stats.get(Statistic::Min), Duration::from_nanos(10));
+        assert_eq!(stats.get(Statistic::P95), Duration::from_nanos(56));
+    }
+
+    #[test]
+    fn stops_at_sample_cap_or_target() {
+        let config = BenchConfig {
+            target: Duration::from_millis(10),
+            max_samples: 100,
+            ..BenchConfig::default()
+        };
+        assert_eq!(config.is_done(5, Duration::from_secs(1)), false);
+        assert_eq!(config.is_done(10, Duration::from_secs(1)), true);
+        assert_eq!(config.is_done(50, Duration::from_millis(1)), false);
+        assert_eq!(config.is_done(100, Duration::from_millis(1)), true);
+    }
+
+    #[test]
+    fn parses_statistic() {
+        assert_eq!("p95".parse::<Statistic>().unwrap(), Statistic::P95);
+        assert_eq!("avg".parse::<Statistic>().is_err(), true);
+    }
+}
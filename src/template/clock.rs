@@ -0,0 +1,22 @@
+/// Source of the current time for the `today` command, so it can be tested against fixed dates.
+use chrono::{DateTime, Utc};
+
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that is stopped at a fixed point in time.
+impl Clock for DateTime<Utc> {
+    fn now(&self) -> DateTime<Utc> {
+        *self
+    }
+}
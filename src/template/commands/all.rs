@@ -1,5 +1,5 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{all_days, run_multi::run_multi, runner::RunnerConfig, Registry};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(solutions: &Registry, config: &RunnerConfig) {
+    run_multi(solutions, &all_days().collect(), config);
 }
@@ -0,0 +1,383 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use regex::Regex;
+
+use crate::template::aoc_cli::get_year;
+use crate::template::{all_days, readme_benchmarks, year, Day};
+
+/// The folders in `data` that belong to a year. Their `.keep` files stay where they are.
+const DATA_FOLDERS: [&str; 4] = ["inputs", "examples", "puzzles", "answers"];
+
+const CONFIG_PATH: &str = ".cargo/config.toml";
+
+/// Archives `year` (or `AOC_YEAR`) and resets the top level for the next year:
+/// the solutions are moved to `src/archive/<year>`, where they are still compiled and tested as
+/// modules of `advent_of_code::archive::y<year>`, their data to `data/<year>` and the benchmark
+/// table of the readme to `src/archive/<year>/README.md`. Archived years run with `--year <year>`.
+///
+/// Every file is read and every target is checked before anything is moved, and the originals are
+/// only removed once their copies are written. If an archive still fails halfway, running the
+/// command again continues it: files that were archived already are skipped.
+pub fn handle(year: Option<u16>) {
+    let Some(year) = year.or_else(get_year) else {
+        eprintln!(
+            "No year to archive, pass `--year <year>` or set `AOC_YEAR` in \"{CONFIG_PATH}\"."
+        );
+        process::exit(1);
+    };
+
+    let archive_dir = year::archive_dir(year);
+    let data_dir = PathBuf::from("data").join(year.to_string());
+
+    let plan = match Plan::new(year, &archive_dir, &data_dir) {
+        Ok(plan) => plan,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if plan.is_empty() {
+        eprintln!("Nothing to archive for {year}.");
+        process::exit(1);
+    }
+
+    let conflicts = plan.conflicts();
+    if !conflicts.is_empty() {
+        for path in &conflicts {
+            eprintln!("\"{}\" exists already.", path.display());
+        }
+        eprintln!("Nothing was archived, move or remove these files first.");
+        process::exit(1);
+    }
+
+    if archive_dir.exists() || data_dir.exists() {
+        println!("Continuing the archive of {year}.");
+    }
+
+    if let Err(e) = plan.execute() {
+        eprintln!("{e}");
+        eprintln!("Run the command again to continue the archive.");
+        process::exit(1);
+    }
+
+    let next_year = year + 1;
+    let bumped_config = bump_year(Path::new(CONFIG_PATH), "AOC_YEAR = ", year);
+    bump_year(Path::new("README.md"), "Advent of Code ", year);
+
+    println!("---");
+    println!(
+        "🎄 Archived {} solution(s) to \"{}\" and {} data file(s) to \"{}\".",
+        plan.solutions.len(),
+        archive_dir.display(),
+        plan.data.len(),
+        data_dir.display()
+    );
+    println!("Type `cargo all --year {year}` to run them, `cargo test` still tests them.");
+    if bumped_config {
+        println!("AOC_YEAR is now {next_year}, good luck!");
+    } else {
+        println!("Remember to set AOC_YEAR to {next_year} in \"{CONFIG_PATH}\".");
+    }
+}
+
+/// A solution that moves into the archive.
+struct SolutionMove {
+    day: Day,
+    /// The module or binary the solution is read from.
+    source: PathBuf,
+    target: PathBuf,
+    /// The solution, pointed at its archived data and modules.
+    contents: String,
+}
+
+/// The readme of the archived year.
+struct ReadmeMove {
+    target: PathBuf,
+    contents: String,
+    /// The top level readme without its benchmark table.
+    /// `None` if the table was moved already.
+    readme: Option<String>,
+}
+
+/// Everything archiving a year writes, moves or removes.
+struct Plan {
+    solutions: Vec<SolutionMove>,
+    data: Vec<(PathBuf, PathBuf)>,
+    readme: Option<ReadmeMove>,
+}
+
+impl Plan {
+    fn new(year: u16, archive_dir: &Path, data_dir: &Path) -> Result<Self, String> {
+        Ok(Self {
+            solutions: plan_solutions(year, archive_dir)?,
+            data: plan_data(data_dir),
+            readme: plan_readme(year, archive_dir)?,
+        })
+    }
+
+    /// Whether the year was archived completely, or had nothing to archive in the first place.
+    fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+            && self.data.is_empty()
+            && self
+                .readme
+                .as_ref()
+                .is_none_or(|readme| readme.readme.is_none())
+    }
+
+    /// The targets that exist already with other contents. A target with the same contents
+    /// is left over from an earlier run that didn't finish.
+    fn conflicts(&self) -> Vec<PathBuf> {
+        let differs = |path: &Path, contents: &str| {
+            path.exists() && fs::read_to_string(path).ok().as_deref() != Some(contents)
+        };
+
+        let solutions = self
+            .solutions
+            .iter()
+            .filter(|solution| differs(&solution.target, &solution.contents))
+            .map(|solution| solution.target.clone());
+
+        // NOTE: a file is renamed in one step, so a rerun never finds both of its paths.
+        let data = self
+            .data
+            .iter()
+            .filter(|(_, to)| to.exists())
+            .map(|(_, to)| to.clone());
+
+        let readme = self
+            .readme
+            .iter()
+            .filter(|readme| readme.readme.is_some() && differs(&readme.target, &readme.contents))
+            .map(|readme| readme.target.clone());
+
+        solutions.chain(data).chain(readme).collect()
+    }
+
+    /// Writes the archive first and only then removes what it replaces.
+    fn execute(&self) -> Result<(), String> {
+        for solution in &self.solutions {
+            write_file(&solution.target, &solution.contents)?;
+        }
+
+        for (from, to) in &self.data {
+            create_parent(to)?;
+            fs::rename(from, to)
+                .map_err(|e| format!("Failed to move \"{}\": {e}", from.display()))?;
+        }
+
+        if let Some(readme) = &self.readme {
+            write_file(&readme.target, &readme.contents)?;
+            if let Some(contents) = &readme.readme {
+                write_file(Path::new("README.md"), contents)?;
+                println!(
+                    "Moved the benchmark table to \"{}\"",
+                    readme.target.display()
+                );
+            }
+        }
+
+        for solution in &self.solutions {
+            for path in [
+                PathBuf::from(format!("src/solutions/day{}.rs", solution.day)),
+                PathBuf::from(format!("src/bin/{}.rs", solution.day)),
+            ] {
+                if path.exists() {
+                    fs::remove_file(&path)
+                        .map_err(|e| format!("Failed to remove \"{}\": {e}", path.display()))?;
+                }
+            }
+            println!(
+                "Moved \"{}\" to \"{}\"",
+                solution.source.display(),
+                solution.target.display()
+            );
+        }
+
+        // NOTE: keeps the emptied folder in git, new days are scaffolded into it.
+        fs::write("src/solutions/.keep", "")
+            .map_err(|e| format!("Failed to keep \"src/solutions\": {e}"))
+    }
+}
+
+/// Reads every day's solution and points it at the archive of `year` in `dir`.
+fn plan_solutions(year: u16, dir: &Path) -> Result<Vec<SolutionMove>, String> {
+    let mut solutions = vec![];
+
+    for day in all_days() {
+        let module_path = PathBuf::from(format!("src/solutions/day{day}.rs"));
+        let bin_path = PathBuf::from(format!("src/bin/{day}.rs"));
+
+        let source = if module_path.exists() {
+            module_path
+        } else if bin_path.exists() {
+            bin_path
+        } else {
+            continue;
+        };
+
+        let contents = fs::read_to_string(&source)
+            .map_err(|e| format!("Failed to read \"{}\": {e}", source.display()))?;
+
+        solutions.push(SolutionMove {
+            day,
+            target: dir.join(format!("day{day}.rs")),
+            contents: rewrite_solution(&contents, year),
+            source,
+        });
+    }
+
+    Ok(solutions)
+}
+
+/// Lists the inputs, examples, puzzles, answers and timings that move into `dir`.
+fn plan_data(dir: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let mut moves: Vec<(PathBuf, PathBuf)> = vec![];
+
+    for folder in DATA_FOLDERS {
+        let Ok(entries) = fs::read_dir(Path::new("data").join(folder)) else {
+            continue;
+        };
+
+        for entry in entries.filter_map(Result::ok) {
+            if entry.file_name() != ".keep" {
+                moves.push((entry.path(), dir.join(folder).join(entry.file_name())));
+            }
+        }
+    }
+
+    let timings_path = Path::new("data").join("timings.json");
+    if timings_path.exists() {
+        moves.push((timings_path, dir.join("timings.json")));
+    }
+
+    moves.sort_unstable();
+    moves
+}
+
+/// Takes the benchmark table of the readme for a readme of the archived year.
+fn plan_readme(year: u16, dir: &Path) -> Result<Option<ReadmeMove>, String> {
+    let mut readme = fs::read_to_string("README.md")
+        .map_err(|e| format!("Failed to read \"README.md\": {e}"))?;
+    let table = readme_benchmarks::take_content(&mut readme)
+        .map_err(|e| format!("Failed to take the benchmark table from the readme: {e:?}"))?;
+
+    let target = dir.join("README.md");
+    let is_taken = table.is_some();
+    if !is_taken && target.exists() {
+        return Ok(None);
+    }
+
+    // NOTE: the archived readme always has a table, so `cargo time --store --year` can update it.
+    let table = table.map_or_else(
+        || "<!--- benchmarking table --->".into(),
+        |table| relink_table(&table),
+    );
+
+    Ok(Some(ReadmeMove {
+        target,
+        contents: format!(
+            "# 🎄 Advent of Code {year}\n\nArchived solutions, type `cargo all --year {year}` to run them. Their data lives in \"data/{year}\".\n\n{table}\n"
+        ),
+        readme: is_taken.then_some(readme),
+    }))
+}
+
+/// Creates the folder `path` goes into.
+fn create_parent(path: &Path) -> Result<(), String> {
+    match path.parent() {
+        Some(parent) => fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create \"{}\": {e}", parent.display())),
+        None => Ok(()),
+    }
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    create_parent(path)?;
+    fs::write(path, contents).map_err(|e| format!("Failed to write \"{}\": {e}", path.display()))
+}
+
+/// Replaces `{prefix}{year}` with the next year in the file at `path`. Returns whether it did.
+fn bump_year(path: &Path, prefix: &str, year: u16) -> bool {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return false;
+    };
+
+    let from = format!("{prefix}{year}");
+    let quoted_from = format!("{prefix}\"{year}\"");
+
+    let bumped = if contents.contains(&quoted_from) {
+        contents.replacen(&quoted_from, &format!("{prefix}\"{}\"", year + 1), 1)
+    } else if contents.contains(&from) {
+        contents.replacen(&from, &format!("{prefix}{}", year + 1), 1)
+    } else {
+        return false;
+    };
+
+    let is_written = fs::write(path, bumped).is_ok();
+    if is_written {
+        println!("Bumped the year in \"{}\" to {}", path.display(), year + 1);
+    }
+    is_written
+}
+
+/// Points a solution at the data and modules of its archived year, e.g.
+/// `read_file("examples", DAY)` becomes `read_file("2024/examples", DAY)`.
+fn rewrite_solution(source: &str, year: u16) -> String {
+    let read_file = Regex::new(r#"(read_file(?:_part)?\(\s*)"([^"]+)""#).unwrap();
+    let modules = Regex::new(r"\bsolutions::day(\d{2})\b").unwrap();
+
+    let source = read_file.replace_all(source, format!(r#"${{1}}"{year}/${{2}}""#));
+    modules
+        .replace_all(&source, format!("archive::y{year}::day${{1}}"))
+        .into_owned()
+}
+
+/// Points the links of a benchmark table at the archived solutions, which sit next to the readme.
+fn relink_table(table: &str) -> String {
+    let links = Regex::new(r"\]\(\./src/(?:solutions/day|bin/)(\d{2})\.rs\)").unwrap();
+    links.replace_all(table, "](./day${1}.rs)").into_owned()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{relink_table, rewrite_solution};
+
+    #[test]
+    fn rewrites_solutions() {
+        let source = [
+            "use advent_of_code::solutions::day03::parse;",
+            "let result = part_one(&advent_of_code::template::read_file(\"examples\", DAY));",
+            "let result = part_two(&advent_of_code::template::read_file_part(",
+            "    \"examples\", DAY, 2,",
+            "));",
+        ]
+        .join("\n");
+
+        let expected = [
+            "use advent_of_code::archive::y2024::day03::parse;",
+            "let result = part_one(&advent_of_code::template::read_file(\"2024/examples\", DAY));",
+            "let result = part_two(&advent_of_code::template::read_file_part(",
+            "    \"2024/examples\", DAY, 2,",
+            "));",
+        ]
+        .join("\n");
+
+        assert_eq!(rewrite_solution(&source, 2024), expected);
+    }
+
+    #[test]
+    fn relinks_tables() {
+        let table =
+            "| [Day 1](./src/solutions/day01.rs) | `1ms` |\n| [Day 2](./src/bin/02.rs) | `2ms` |";
+        assert_eq!(
+            relink_table(table),
+            "| [Day 1](./day01.rs) | `1ms` |\n| [Day 2](./day02.rs) | `2ms` |"
+        );
+    }
+}
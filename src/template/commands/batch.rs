@@ -0,0 +1,170 @@
+use std::fs;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use crate::template::commands::{download, scaffold};
+use crate::template::{solution_path, Day, ANSI_BOLD, ANSI_RESET};
+
+/// Minimum time between two requests to aoc-cli, to go easy on the servers.
+const DOWNLOAD_INTERVAL: Duration = Duration::from_secs(3);
+
+/// How often a failed download is retried before the day is reported as failed.
+const DOWNLOAD_RETRIES: u32 = 2;
+
+/// What happened to one step of a day.
+enum Step {
+    Done,
+    Skipped(&'static str),
+    Failed(String),
+    NotRun,
+}
+
+struct DaySummary {
+    day: Day,
+    solution: Step,
+    input: Step,
+}
+
+/// Scaffolds and / or downloads several days, e.g. to catch up on a past year.
+/// Days that already have a solution are not scaffolded unless `overwrite` is set, and inputs
+/// that were already downloaded are skipped. Prints a summary of all days at the end and exits
+/// non-zero if any of them failed.
+pub fn handle(days: &[Day], scaffold: bool, download: bool, overwrite: bool) {
+    if download {
+        download::check_aoc_cli();
+    }
+
+    let mut summaries: Vec<DaySummary> = Vec::with_capacity(days.len());
+    let mut is_first_download = true;
+
+    for day in days {
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let solution = if !scaffold {
+            Step::NotRun
+        } else if !overwrite && solution_path(*day).exists() {
+            println!("Skipped, the solution already exists.");
+            Step::Skipped("exists")
+        } else {
+            match scaffold::scaffold(*day, overwrite) {
+                Ok(()) => Step::Done,
+                Err(e) => {
+                    eprintln!("{e}");
+                    Step::Failed(e)
+                }
+            }
+        };
+
+        let input = if !download || matches!(solution, Step::Failed(_)) {
+            Step::NotRun
+        } else if has_input(*day) {
+            println!("Skipped download, the input already exists.");
+            Step::Skipped("exists")
+        } else {
+            if !is_first_download {
+                thread::sleep(DOWNLOAD_INTERVAL);
+            }
+            is_first_download = false;
+
+            match download::download_with_retries(*day, DOWNLOAD_RETRIES) {
+                Ok(()) => Step::Done,
+                Err(e) => {
+                    eprintln!("failed to call aoc-cli: {e}");
+                    Step::Failed(e.to_string())
+                }
+            }
+        };
+
+        println!();
+        summaries.push(DaySummary {
+            day: *day,
+            solution,
+            input,
+        });
+    }
+
+    print_summary(&summaries, scaffold, download);
+
+    if summaries.iter().any(DaySummary::is_failed) {
+        process::exit(1);
+    }
+}
+
+/// Whether the input of `day` was downloaded already. Scaffolding creates empty input files.
+fn has_input(day: Day) -> bool {
+    fs::metadata(format!("data/inputs/{day}.txt")).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+impl DaySummary {
+    fn is_failed(&self) -> bool {
+        matches!(self.solution, Step::Failed(_)) || matches!(self.input, Step::Failed(_))
+    }
+
+    fn is_done(&self) -> bool {
+        matches!(self.solution, Step::Done) || matches!(self.input, Step::Done)
+    }
+}
+
+fn format_step(step: &Step, done: &str) -> String {
+    match step {
+        Step::Done => done.into(),
+        Step::Skipped(reason) => format!("skipped ({reason})"),
+        Step::Failed(e) => format!("failed: {}", e.lines().next().unwrap_or_default()),
+        Step::NotRun => "-".into(),
+    }
+}
+
+fn print_summary(summaries: &[DaySummary], scaffold: bool, download: bool) {
+    println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("-------");
+
+    let mut header = String::from("Day");
+    if scaffold {
+        header.push_str(&format!("  {:18}", "Solution"));
+    }
+    if download {
+        header.push_str("  Input");
+    }
+    println!("{}", header.trim_end());
+
+    for summary in summaries {
+        let mut line = format!("{:3}", summary.day.to_string());
+        if scaffold {
+            line.push_str(&format!(
+                "  {:18}",
+                format_step(&summary.solution, "created")
+            ));
+        }
+        if download {
+            line.push_str(&format!("  {}", format_step(&summary.input, "downloaded")));
+        }
+        println!("{}", line.trim_end());
+    }
+
+    let count =
+        |predicate: fn(&DaySummary) -> bool| summaries.iter().filter(|s| predicate(s)).count();
+
+    let mut counts = vec![];
+    if scaffold {
+        counts.push(format!(
+            "{} created",
+            count(|s| matches!(s.solution, Step::Done))
+        ));
+    }
+    if download {
+        counts.push(format!(
+            "{} downloaded",
+            count(|s| matches!(s.input, Step::Done))
+        ));
+    }
+    counts.push(format!(
+        "{} skipped",
+        count(|s| !s.is_failed() && !s.is_done())
+    ));
+    counts.push(format!("{} failed", count(DaySummary::is_failed)));
+
+    println!();
+    println!("Days: {}.", counts.join(", "));
+}
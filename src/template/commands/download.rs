@@ -1,14 +1,52 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::aoc_cli::{self, AocCommandError};
+use crate::template::Day;
 use std::process;
+use std::thread;
+use std::time::Duration;
 
 pub fn handle(day: Day) {
+    handle_with_retries(day, 0);
+}
+
+/// Like [`handle`], but retries failed downloads up to `retries` times with exponential backoff,
+/// e.g. because the puzzle just unlocked and the server is busy.
+pub fn handle_with_retries(day: Day, retries: u32) {
+    check_aoc_cli();
+
+    if let Err(e) = download_with_retries(day, retries) {
+        eprintln!("failed to call aoc-cli: {e}");
+        process::exit(1);
+    }
+}
+
+/// Exits if aoc-cli is not installed.
+pub fn check_aoc_cli() {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
+}
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+/// Downloads the input and puzzle of `day`, retrying up to `retries` times with exponential backoff.
+/// Returns the error of the last attempt.
+pub fn download_with_retries(day: Day, retries: u32) -> Result<(), AocCommandError> {
+    let mut delay = Duration::from_secs(2);
+
+    for attempt in 0..retries {
+        match aoc_cli::download(day) {
+            Ok(_) => return Ok(()),
+            Err(e) => {
+                eprintln!(
+                    "failed to call aoc-cli: {e} Retrying in {}s (attempt {} of {}).",
+                    delay.as_secs(),
+                    attempt + 1,
+                    retries + 1
+                );
+                thread::sleep(delay);
+                delay *= 2;
+            }
+        }
+    }
+
+    aoc_cli::download(day).map(|_| ())
 }
@@ -0,0 +1,63 @@
+use std::process;
+
+use crate::template::answers::{Answers, Verification};
+use crate::template::input::InputSource;
+use crate::template::run_multi::{run_day_silent, DayOutcome};
+use crate::template::runner::{format_part_result, RunnerConfig};
+use crate::template::{Day, Registry, ANSI_BOLD, ANSI_RESET};
+
+/// Runs solutions against their examples in `data/examples` and checks the answers against the
+/// expected ones in the sidecar files, e.g. `data/examples/01.answers.json`.
+/// Exits non-zero if any answer does not match, or if the example of a day with expected answers
+/// is missing. The input source of the `config` is ignored.
+pub fn handle(solutions: &Registry, days: &[Day], config: &RunnerConfig) {
+    let config = RunnerConfig {
+        input: InputSource::Set("examples".into()),
+        ..config.clone()
+    };
+    let mut failures = 0;
+
+    for (index, day) in days.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let results = match run_day_silent(solutions, *day, &config) {
+            DayOutcome::NotSolved => {
+                println!("Not solved.");
+                continue;
+            }
+            DayOutcome::MissingInput(e) => {
+                eprintln!("Could not open example file: {e}");
+                if !Answers::read_example_file(*day).is_empty() {
+                    failures += 1;
+                }
+                continue;
+            }
+            DayOutcome::Ran(results) => results,
+        };
+
+        let expected = Answers::read_example_file(*day);
+
+        for result in &results {
+            let line = format_part_result(result, config.verbosity);
+            let check = match expected.verify(result) {
+                Verification::Match => "✔".to_string(),
+                Verification::Mismatch { expected, .. } => {
+                    failures += 1;
+                    format!("✖ expected {expected}")
+                }
+                Verification::Unknown => "(no expected answer)".to_string(),
+            };
+            println!("{} {check}", line.trim_end());
+        }
+    }
+
+    if failures > 0 {
+        println!();
+        println!("{ANSI_BOLD}{failures} example(s) failed.{ANSI_RESET}");
+        process::exit(1);
+    }
+}
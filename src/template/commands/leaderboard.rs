@@ -0,0 +1,202 @@
+use std::fs;
+use std::process;
+
+use crate::template::leaderboard::{Leaderboard, Member};
+use crate::template::{all_days, format_seconds, Day, Part, ANSI_BOLD, ANSI_RESET};
+
+/// Names are cut off after this many characters to keep the tables aligned.
+const MAX_NAME_LEN: usize = 24;
+
+/// Renders a private leaderboard from its JSON export at `path`: the standings with recomputed
+/// local scores, followed by the star times of every day (or only of `day`).
+pub fn handle(path: &str, day: Option<Day>) {
+    let leaderboard = match fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| Leaderboard::try_from(contents.as_str()))
+    {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("Failed to read leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    print_standings(&leaderboard);
+
+    let days: Vec<Day> = match day {
+        Some(day) => vec![day],
+        None => all_days().collect(),
+    };
+
+    for day in days {
+        print_day(&leaderboard, day);
+    }
+}
+
+fn print_standings(leaderboard: &Leaderboard) {
+    let scores = leaderboard.local_scores();
+
+    let mut members: Vec<&Member> = leaderboard.members.iter().collect();
+    // NOTE: ties are broken by who collected their latest star first, like on the website.
+    members.sort_by_key(|member| {
+        (
+            std::cmp::Reverse(scores[&member.id]),
+            member.last_star_ts,
+            member.id,
+        )
+    });
+
+    let width = name_width(&members);
+    let rank_width = members.len().to_string().len();
+    let mut has_mismatches = false;
+
+    println!(
+        "{ANSI_BOLD}Private leaderboard {}{ANSI_RESET}",
+        leaderboard.event
+    );
+    // NOTE: day numbers are printed vertically above the star grid, tens above ones.
+    let tens: String = all_days()
+        .map(|day| match day.into_inner() / 10 {
+            0 => ' ',
+            tens => char::from(b'0' + tens),
+        })
+        .collect();
+    let ones: String = all_days()
+        .map(|day| char::from(b'0' + day.into_inner() % 10))
+        .collect();
+
+    println!("{:rank_width$}  {:width$}  Score  Stars  {tens}", "", "");
+    println!("{:rank_width$}  {:width$}                {ones}", "", "");
+
+    for (index, member) in members.iter().enumerate() {
+        let score = scores[&member.id];
+        let mismatch = if score == member.local_score {
+            " "
+        } else {
+            has_mismatches = true;
+            "*"
+        };
+
+        let stars: String = all_days()
+            .map(|day| {
+                match (
+                    member.star(day, Part::One).is_some(),
+                    member.star(day, Part::Two).is_some(),
+                ) {
+                    (true, true) => '★',
+                    (true, false) => '☆',
+                    _ => '·',
+                }
+            })
+            .collect();
+
+        println!(
+            "{:>rank_width$}) {:width$}  {score:>4}{mismatch}  {:>5}  {stars}",
+            index + 1,
+            truncate(&member.display_name()),
+            member.stars,
+        );
+    }
+
+    if has_mismatches {
+        println!();
+        println!(
+            "* the recomputed score differs from the exported one, e.g. because members left."
+        );
+    }
+}
+
+fn print_day(leaderboard: &Leaderboard, day: Day) {
+    let mut members: Vec<&Member> = leaderboard
+        .members
+        .iter()
+        .filter(|member| member.star(day, Part::One).is_some())
+        .collect();
+
+    if members.is_empty() {
+        return;
+    }
+
+    // NOTE: members who finished both parts come first, by the time of their second star.
+    members.sort_by_key(|member| {
+        (
+            member
+                .star(day, Part::Two)
+                .map_or(u64::MAX, |s| s.timestamp),
+            member.star(day, Part::One).map(|s| s.timestamp),
+        )
+    });
+
+    let width = name_width(&members);
+    let unlock = leaderboard.unlock_timestamp(day);
+
+    println!();
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+
+    for member in members {
+        let part_1 = format_star_time(member.star(day, Part::One).map(|s| s.timestamp), unlock);
+        let part_2 = match (member.star(day, Part::Two), member.part_delta(day)) {
+            (Some(star), Some(delta)) => format!(
+                "{} (+{})",
+                format_star_time(Some(star.timestamp), unlock),
+                format_seconds(delta)
+            ),
+            _ => "-".into(),
+        };
+
+        println!(
+            "{:width$}  Part 1: {part_1:>12}  Part 2: {part_2}",
+            truncate(&member.display_name())
+        );
+    }
+}
+
+fn name_width(members: &[&Member]) -> usize {
+    members
+        .iter()
+        .map(|member| truncate(&member.display_name()).chars().count())
+        .max()
+        .unwrap_or_default()
+}
+
+fn truncate(name: &str) -> String {
+    if name.chars().count() > MAX_NAME_LEN {
+        let mut truncated: String = name.chars().take(MAX_NAME_LEN - 1).collect();
+        truncated.push('…');
+        truncated
+    } else {
+        name.to_string()
+    }
+}
+
+/// Formats when a star was collected, relative to the unlock of the puzzle if it's known.
+fn format_star_time(timestamp: Option<u64>, unlock: Option<u64>) -> String {
+    match (timestamp, unlock) {
+        (Some(timestamp), Some(unlock)) if timestamp >= unlock => {
+            format_seconds(timestamp - unlock)
+        }
+        (Some(timestamp), _) => format!("@{timestamp}"),
+        (None, _) => "-".into(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_star_time, truncate};
+
+    #[test]
+    fn formats_star_times() {
+        assert_eq!(format_star_time(Some(160), Some(100)), "00:01:00");
+        assert_eq!(format_star_time(Some(50), Some(100)), "@50");
+        assert_eq!(format_star_time(None, Some(100)), "-");
+    }
+
+    #[test]
+    fn truncates_long_names() {
+        assert_eq!(truncate("Alice"), "Alice");
+        assert_eq!(truncate(&"a".repeat(30)).chars().count(), 24);
+    }
+}
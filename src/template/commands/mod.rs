@@ -1,6 +1,15 @@
 pub mod all;
+pub mod archive;
+pub mod batch;
 pub mod download;
+pub mod example;
+pub mod leaderboard;
 pub mod read;
+pub mod run;
 pub mod scaffold;
+pub mod serve;
 pub mod solve;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
+pub mod verify;
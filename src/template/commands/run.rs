@@ -0,0 +1,14 @@
+use std::collections::HashSet;
+
+use crate::template::runner::RunnerConfig;
+use crate::template::{all_days, run_multi::run_multi, Day, Registry};
+
+pub fn handle(solutions: &Registry, days: &[Day], config: &RunnerConfig) {
+    let days_to_run: HashSet<Day> = if days.is_empty() {
+        all_days().collect()
+    } else {
+        days.iter().copied().collect()
+    };
+
+    run_multi(solutions, &days_to_run, config);
+}
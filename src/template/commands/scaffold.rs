@@ -4,11 +4,18 @@ use std::{
     process,
 };
 
+use crate::template::answers::Answers;
 use crate::template::Day;
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+/// Runs the day's library module, see [`solution!`](crate::solution).
+const BIN_TEMPLATE: &str = "fn main() {
+    advent_of_code::solutions::day%DAY%::main();
+}
+";
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -28,52 +35,50 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 pub fn handle(day: Day, overwrite: bool) {
+    if let Err(e) = scaffold(day, overwrite) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    println!("---");
+    println!("🎄 Type `cargo solve {day}` to run your solution.");
+}
+
+/// Creates the solution, input and example files of `day`, printing every created file.
+/// Existing solution files are only replaced if `overwrite` is set.
+pub fn scaffold(day: Day, overwrite: bool) -> Result<(), String> {
     let input_path = format!("data/inputs/{day}.txt");
     let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+    let example_answers_path = format!("data/examples/{day}.answers.json");
+    let module_path = format!("src/solutions/day{day}.rs");
+    let bin_path = format!("src/bin/{day}.rs");
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
-    };
+    safe_create_file(&module_path, overwrite)
+        .map_err(|e| format!("Failed to create module file: {e}"))?
+        .write_all(
+            MODULE_TEMPLATE
+                .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+                .as_bytes(),
+        )
+        .map_err(|e| format!("Failed to write module contents: {e}"))?;
+    println!("Created module file \"{}\"", &module_path);
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
-    }
+    safe_create_file(&bin_path, overwrite)
+        .map_err(|e| format!("Failed to create binary file: {e}"))?
+        .write_all(BIN_TEMPLATE.replace("%DAY%", &day.to_string()).as_bytes())
+        .map_err(|e| format!("Failed to write binary contents: {e}"))?;
+    println!("Created binary file \"{}\"", &bin_path);
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
-    }
+    create_file(&input_path).map_err(|e| format!("Failed to create input file: {e}"))?;
+    println!("Created empty input file \"{}\"", &input_path);
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
-    }
+    create_file(&example_path).map_err(|e| format!("Failed to create example file: {e}"))?;
+    println!("Created empty example file \"{}\"", &example_path);
 
-    println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    Answers::default()
+        .store_example_file(day)
+        .map_err(|e| format!("Failed to create example answers file: {e}"))?;
+    println!("Created example answers file \"{}\"", &example_answers_path);
+
+    Ok(())
 }
@@ -0,0 +1,396 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process;
+use std::sync::mpsc::{self, TrySendError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tinyjson::JsonValue;
+
+use crate::template::run_multi::{collect_timing, run_part_silent};
+use crate::template::runner::RunnerConfig;
+use crate::template::{Day, Part, Registry};
+
+/// Largest accepted request body, puzzle inputs are far smaller.
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// Longest accepted request line or header line, including its line break.
+const MAX_LINE_BYTES: u64 = 8 * 1024;
+
+/// Connections that don't send a complete request within this time are closed,
+/// no matter how slowly they keep sending.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Parts that run longer are abandoned unless another `--timeout` is passed,
+/// so a part that never finishes doesn't keep a worker busy forever.
+const DEFAULT_PART_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Number of threads that handle requests.
+const WORKERS: usize = 4;
+
+/// Connections that wait for a free worker. Further connections are turned away with a `503`.
+const QUEUE_LEN: usize = 16;
+
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+struct Response {
+    status: u16,
+    body: JsonValue,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: JsonValue::Object(HashMap::from([(
+                "error".to_string(),
+                JsonValue::String(message.into()),
+            )])),
+        }
+    }
+}
+
+/// Serves solutions over HTTP until the process is stopped.
+/// `POST /days/<day>/parts/<part>` runs a part against the request body and responds with
+/// its result record and timing as JSON.
+pub fn handle(solutions: &Registry, host: &str, port: u16, config: &RunnerConfig) {
+    let config = &RunnerConfig {
+        timeout: config.timeout.or(Some(DEFAULT_PART_TIMEOUT)),
+        ..config.clone()
+    };
+
+    let listener = match TcpListener::bind((host, port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Failed to listen on {host}:{port}: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("Serving solutions on http://{host}:{port}");
+    println!(
+        "Example: curl --data-binary @data/inputs/01.txt http://{host}:{port}/days/01/parts/1"
+    );
+
+    let (sender, receiver) = mpsc::sync_channel::<TcpStream>(QUEUE_LEN);
+    let receiver = Mutex::new(receiver);
+
+    thread::scope(|scope| {
+        for _ in 0..WORKERS {
+            let receiver = &receiver;
+            scope.spawn(move || loop {
+                // NOTE: the lock is released before the connection is handled.
+                let Ok(stream) = receiver.lock().unwrap().recv() else {
+                    break;
+                };
+                handle_connection(solutions, stream, config);
+            });
+        }
+
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    if let Err(TrySendError::Full(mut stream)) = sender.try_send(stream) {
+                        let _ = stream.set_write_timeout(Some(REQUEST_TIMEOUT));
+                        let busy = Response::error(503, "server is busy, try again later");
+                        let _ = write_response(&mut stream, &busy);
+                    }
+                }
+                Err(e) => eprintln!("Failed to accept connection: {e}"),
+            }
+        }
+    });
+}
+
+fn handle_connection(solutions: &Registry, mut stream: TcpStream, config: &RunnerConfig) {
+    let _ = stream.set_write_timeout(Some(REQUEST_TIMEOUT));
+
+    let reader = DeadlineReader {
+        stream: &stream,
+        deadline: Instant::now() + REQUEST_TIMEOUT,
+    };
+
+    let response = match read_request(&mut BufReader::new(reader)) {
+        Ok(request) => respond(solutions, &request, config),
+        Err(response) => response,
+    };
+
+    if let Err(e) = write_response(&mut stream, &response) {
+        eprintln!("Failed to send response: {e}");
+    }
+}
+
+/// Reads from a connection until a deadline for the whole request, so a client can't hold a
+/// worker by sending a byte at a time.
+struct DeadlineReader<'a> {
+    stream: &'a TcpStream,
+    deadline: Instant,
+}
+
+impl Read for DeadlineReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+
+        self.stream.set_read_timeout(Some(remaining))?;
+        self.stream.read(buf)
+    }
+}
+
+/// Reads an HTTP/1.1 request. Malformed requests are answered with the returned error response.
+fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let read_line = |reader: &mut dyn BufRead| {
+        let mut line = String::new();
+        match reader.take(MAX_LINE_BYTES).read_line(&mut line) {
+            Ok(0) | Err(_) => Err(Response::error(400, "incomplete request")),
+            Ok(len) if len as u64 == MAX_LINE_BYTES && !line.ends_with('\n') => {
+                Err(Response::error(431, "request line or header is too long"))
+            }
+            Ok(_) => Ok(line.trim_end().to_string()),
+        }
+    };
+
+    let request_line = read_line(reader)?;
+    let (method, path) = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+        [method, path, _version] => (method.to_string(), path.to_string()),
+        _ => return Err(Response::error(400, "malformed request line")),
+    };
+
+    let mut content_length = 0;
+
+    loop {
+        let header = read_line(reader)?;
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| Response::error(400, "invalid content-length"))?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(Response::error(413, "request body is too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|_| Response::error(400, "incomplete request body"))?;
+
+    let body = String::from_utf8(body)
+        .map_err(|_| Response::error(400, "request body is not valid UTF-8"))?;
+
+    Ok(Request { method, path, body })
+}
+
+/// Parses a path of the form `/days/<day>/parts/<part>`, ignoring query strings.
+/// Returns `None` for other paths.
+fn parse_route(path: &str) -> Option<Result<(Day, Part), String>> {
+    let path = path.split('?').next().unwrap_or_default();
+
+    match path.split('/').collect::<Vec<_>>()[..] {
+        ["", "days", day, "parts", part] => Some(
+            day.parse()
+                .map_err(|e| format!("{e}"))
+                .and_then(|day| Ok((day, part.parse().map_err(|e| format!("{e}"))?))),
+        ),
+        _ => None,
+    }
+}
+
+fn respond(solutions: &Registry, request: &Request, config: &RunnerConfig) -> Response {
+    let (day, part) = match parse_route(&request.path) {
+        None => return Response::error(404, "not found, use `POST /days/<day>/parts/<part>`"),
+        Some(Err(e)) => return Response::error(400, &e),
+        Some(Ok(route)) => route,
+    };
+
+    if request.method != "POST" {
+        return Response::error(405, "method not allowed, use POST");
+    }
+
+    let Some(result) = run_part_silent(solutions, day, part, &request.body, config) else {
+        return Response::error(404, &format!("day {day} has no solution for part {part}"));
+    };
+
+    let mut body = JsonValue::from(&result);
+    if let JsonValue::Object(map) = &mut body {
+        map.insert(
+            "timing".into(),
+            JsonValue::from(&collect_timing(
+                day,
+                &[result],
+                None,
+                config.bench.map(|bench| bench.statistic),
+            )),
+        );
+    }
+
+    Response { status: 200, body }
+}
+
+fn write_response(stream: &mut impl Write, response: &Response) -> std::io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    let body = response.body.stringify().unwrap_or_default();
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        response.status,
+        body.len()
+    )?;
+    stream.flush()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io::Cursor;
+
+    use tinyjson::JsonValue;
+
+    use super::{parse_route, read_request, respond, Request};
+    use crate::day;
+    use crate::template::runner::{run_part, PartResult, RunnerConfig};
+    use crate::template::{Day, Part, Solution};
+
+    struct CountChars;
+
+    impl Solution for CountChars {
+        fn day(&self) -> Day {
+            day!(1)
+        }
+
+        fn parts(&self) -> &'static [Part] {
+            &[Part::One]
+        }
+
+        fn run_part(&self, part: Part, input: &str, config: &RunnerConfig) -> Option<PartResult> {
+            Some(run_part(
+                |input: &str| Some(input.len()),
+                input,
+                self.day(),
+                part,
+                config,
+            ))
+        }
+    }
+
+    static SOLUTIONS: &[&dyn Solution] = &[&CountChars];
+
+    fn post(path: &str, body: &str) -> Request {
+        Request {
+            method: "POST".into(),
+            path: path.into(),
+            body: body.into(),
+        }
+    }
+
+    #[test]
+    fn reads_requests() {
+        let raw =
+            "POST /days/01/parts/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: 5\r\n\r\nabcde";
+        let request = read_request(&mut Cursor::new(raw)).ok().unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/days/01/parts/1");
+        assert_eq!(request.body, "abcde");
+    }
+
+    #[test]
+    fn rejects_incomplete_requests() {
+        let raw = "POST /days/01/parts/1 HTTP/1.1\r\nContent-Length: 10\r\n\r\nabc";
+        assert_eq!(
+            read_request(&mut Cursor::new(raw)).err().unwrap().status,
+            400
+        );
+    }
+
+    #[test]
+    fn rejects_long_lines() {
+        let raw = format!(
+            "POST /days/01/parts/1 HTTP/1.1\r\nX-Long: {}",
+            "a".repeat(10_000)
+        );
+        assert_eq!(
+            read_request(&mut Cursor::new(raw)).err().unwrap().status,
+            431
+        );
+
+        let raw = format!("POST /{} HTTP/1.1\r\n\r\n", "a".repeat(10_000));
+        assert_eq!(
+            read_request(&mut Cursor::new(raw)).err().unwrap().status,
+            431
+        );
+    }
+
+    #[test]
+    fn parses_routes() {
+        assert_eq!(
+            parse_route("/days/5/parts/2?x=1"),
+            Some(Ok((day!(5), Part::Two)))
+        );
+        assert!(matches!(parse_route("/days/26/parts/1"), Some(Err(_))));
+        assert_eq!(parse_route("/days/5"), None);
+    }
+
+    #[test]
+    fn runs_parts() {
+        let response = respond(
+            SOLUTIONS,
+            &post("/days/01/parts/1", "abc"),
+            &RunnerConfig::default(),
+        );
+        assert_eq!(response.status, 200);
+
+        let JsonValue::Object(body) = response.body else {
+            panic!("expected a JSON object");
+        };
+        assert_eq!(body["answer"], JsonValue::String("3".into()));
+        assert_eq!(body["status"], JsonValue::String("solved".into()));
+        assert!(matches!(body["timing"], JsonValue::Object(_)));
+    }
+
+    #[test]
+    fn responds_with_errors() {
+        let config = RunnerConfig::default();
+        assert_eq!(
+            respond(SOLUTIONS, &post("/days/01/parts/2", ""), &config).status,
+            404
+        );
+        assert_eq!(
+            respond(SOLUTIONS, &post("/days/02/parts/1", ""), &config).status,
+            404
+        );
+        assert_eq!(respond(SOLUTIONS, &post("/foo", ""), &config).status, 404);
+
+        let get = Request {
+            method: "GET".into(),
+            ..post("/days/01/parts/1", "")
+        };
+        assert_eq!(respond(SOLUTIONS, &get, &config).status, 405);
+    }
+}
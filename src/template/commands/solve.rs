@@ -1,30 +1,133 @@
-use std::process::{Command, Stdio};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::input::InputSource;
+use crate::template::runner::Verbosity;
+use crate::template::watch::{files_in, watch};
+use crate::template::{solution_path, Day, Part};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+/// How the allocations of a solution are profiled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllocProfiler {
+    None,
+    /// Full heap profiling with DHAT, writes `dhat-heap.json`.
+    Dhat,
+    /// Lightweight counting allocator, reports allocations next to each part.
+    Count,
+}
+
+/// Runs the solution of a day, or its tests if `test` is set.
+/// With `watch`, it re-runs whenever the day's source, the shared library or the day's data changes.
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    day: Day,
+    release: bool,
+    profiler: AllocProfiler,
+    json: bool,
+    verbosity: Verbosity,
+    input: &InputSource,
+    submit_part: Option<Part>,
+    watch_files: bool,
+    test: bool,
+) {
+    // NOTE: `aoc_debug!` is compiled out of optimized builds, which includes the `dhat` profile.
+    let is_optimized = release || profiler == AllocProfiler::Dhat;
+    if !test && is_optimized && verbosity == Verbosity::Verbose {
+        eprintln!("`aoc_debug!` output is not part of optimized builds, drop `--release` or `--dhat` to see it with `--verbose`.");
+    }
+
+    let cmd_args = if test {
+        build_test_args(day)
+    } else {
+        build_run_args(day, release, profiler, json, verbosity, input, submit_part)
+    };
+
+    if !watch_files {
+        run_cargo(&cmd_args);
+        return;
+    }
+
+    if submit_part.is_some() {
+        eprintln!("`--watch` can not be combined with `--submit`.");
+        process::exit(1);
+    }
+
+    if *input == InputSource::Stdin {
+        eprintln!("`--watch` can not be combined with `--stdin`.");
+        process::exit(1);
+    }
+
+    watch(|| watched_files(day, input), || run_cargo(&cmd_args));
+}
+
+/// Tests of library solutions are run as part of the library, filtered to the day's module.
+fn build_test_args(day: Day) -> Vec<String> {
+    if solution_path(day).starts_with("src/solutions") {
+        vec![
+            "test".to_string(),
+            "--lib".to_string(),
+            format!("solutions::day{day}::"),
+        ]
+    } else {
+        vec!["test".to_string(), "--bin".to_string(), day.to_string()]
+    }
+}
+
+fn build_run_args(
+    day: Day,
+    release: bool,
+    profiler: AllocProfiler,
+    json: bool,
+    verbosity: Verbosity,
+    input: &InputSource,
+    submit_part: Option<Part>,
+) -> Vec<String> {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
-    if dhat {
-        cmd_args.extend([
-            "--profile".to_string(),
-            "dhat".to_string(),
-            "--features".to_string(),
-            "dhat-heap".to_string(),
-        ]);
-    } else if release {
+    match profiler {
+        AllocProfiler::Dhat => {
+            cmd_args.extend([
+                "--profile".to_string(),
+                "dhat".to_string(),
+                "--features".to_string(),
+                "dhat-heap".to_string(),
+            ]);
+        }
+        AllocProfiler::Count => {
+            cmd_args.extend(["--features".to_string(), "count-allocs".to_string()]);
+        }
+        AllocProfiler::None => {}
+    }
+
+    if release && profiler != AllocProfiler::Dhat {
         cmd_args.push("--release".to_string());
     }
 
     cmd_args.push("--".to_string());
 
+    if json {
+        cmd_args.push("--json".to_string());
+    }
+
+    match verbosity {
+        Verbosity::Quiet => cmd_args.push("--quiet".to_string()),
+        Verbosity::Verbose => cmd_args.push("--verbose".to_string()),
+        Verbosity::Normal => {}
+    }
+
+    cmd_args.extend(input.to_args());
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
+    cmd_args
+}
+
+fn run_cargo(cmd_args: &[String]) {
     let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+        .args(cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
@@ -32,3 +135,27 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// The day's binary, the library including all solution modules and the day's files in `data/`.
+fn watched_files(day: Day, input: &InputSource) -> Vec<PathBuf> {
+    let day_prefix = day.to_string();
+
+    let mut files: Vec<PathBuf> = files_in(Path::new("src"))
+        .into_iter()
+        .filter(|path| !path.starts_with("src/bin"))
+        .collect();
+
+    files.push(PathBuf::from(format!("src/bin/{day}.rs")));
+
+    files.extend(files_in(Path::new("data")).into_iter().filter(|path| {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(&day_prefix))
+    }));
+
+    if let InputSource::File(path) = input {
+        files.push(path.clone());
+    }
+
+    files
+}
@@ -1,34 +1,68 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::runner::RunnerConfig;
+use crate::template::timings::{PartComparison, Timings};
+use crate::template::{all_days, readme_benchmarks, Day, Registry, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+/// Benches solutions. If a `compare_threshold` is passed, the new timings are compared
+/// with the stored ones and the process exits non-zero if a part got slower than the threshold.
+/// The `config` is expected to have benchmark settings.
+pub fn handle(
+    solutions: &Registry,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    compare_threshold: Option<f64>,
+    show_memory: bool,
+    config: &RunnerConfig,
+) {
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
                 all_days().collect()
+            } else if compare_threshold.is_some() {
+                // when comparing, re-run every day that has stored timings.
+                stored_timings.data.iter().map(|t| t.day).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
                 all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                    .filter(|day| !stored_timings.is_day_complete(day))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    if compare_threshold.is_some() {
+        let statistic = config.bench.unwrap_or_default().statistic;
+        // NOTE: refuse before benching, e.g. a median baseline can't tell if a mean got slower.
+        let others = stored_timings.other_statistics(&days_to_run, statistic);
+        if !others.is_empty() {
+            eprintln!("Can't compare the {statistic} with stored timings of another statistic:");
+            for (day, other) in others {
+                eprintln!("Day {day}: {other}");
+            }
+            eprintln!("Pass the same `--stat <statistic>`, or `--store` new timings first.");
+            process::exit(1);
+        }
+    }
+
+    let timings = run_multi(solutions, &days_to_run, config).unwrap();
+
+    let has_regressions = compare_threshold
+        .is_some_and(|threshold| print_comparison(&stored_timings.compare(&timings), threshold));
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(merged_timings, show_memory) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +71,54 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if has_regressions {
+        process::exit(1);
+    }
+}
+
+/// Prints the relative change of every part. Returns `true` if any part regressed.
+fn print_comparison(comparisons: &[PartComparison], threshold: f64) -> bool {
+    println!();
+    println!(
+        "{ANSI_BOLD}Comparison with stored timings{ANSI_RESET} (threshold: +{:.1}%)",
+        threshold * 100_f64
+    );
+
+    if comparisons.is_empty() {
+        println!("No stored timings to compare with.");
+        return false;
+    }
+
+    let mut regressions = 0;
+
+    for comparison in comparisons {
+        let is_regression = comparison.is_regression(threshold);
+        if is_regression {
+            regressions += 1;
+        }
+
+        println!(
+            "Day {} / Part {}: {} → {} ({}{:+.1}%{}){}",
+            comparison.day,
+            comparison.part,
+            format_nanos(comparison.old_nanos),
+            format_nanos(comparison.new_nanos),
+            if is_regression { ANSI_BOLD } else { "" },
+            comparison.change() * 100_f64,
+            if is_regression { ANSI_RESET } else { "" },
+            if is_regression { " ✖ regression" } else { "" }
+        );
+    }
+
+    if regressions > 0 {
+        eprintln!("\n{regressions} part(s) got slower than the threshold.");
+    }
+
+    regressions > 0
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
 }
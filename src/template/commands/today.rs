@@ -0,0 +1,66 @@
+use std::io::{stdout, Write};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use crate::template::aoc_cli::get_year;
+use crate::template::clock::{Clock, SystemClock};
+use crate::template::commands::{download, read, scaffold};
+use crate::template::{format_seconds, Day, TodayError};
+
+/// How often the download is retried after waiting for a puzzle to unlock.
+const DOWNLOAD_RETRIES: u32 = 4;
+
+/// Scaffolds, downloads and reads the current day.
+/// If no puzzle is unlocked today and `wait` is set, counts down to the next unlock first.
+pub fn handle(wait: bool) {
+    let (day, waited) = match Day::today() {
+        Ok(day) => (day, false),
+        Err(TodayError::OutsideAdvent) if wait => (wait_for_unlock(&SystemClock), true),
+        Err(TodayError::OutsideAdvent) => {
+            eprintln!(
+                "`today` command can only be run between the 1st and \
+                the 25th of december. Please use `scaffold` with a specific day \
+                or pass `--wait` to wait for the next puzzle."
+            );
+            process::exit(1)
+        }
+        Err(e) => {
+            eprintln!(
+                "`today` command can't be used: {e}. Please use `scaffold` with a specific day."
+            );
+            process::exit(1)
+        }
+    };
+
+    scaffold::handle(day, false);
+    // NOTE: inputs are not always available right at unlock, retry after waiting for it.
+    download::handle_with_retries(day, if waited { DOWNLOAD_RETRIES } else { 0 });
+    read::handle(day);
+}
+
+/// Shows a countdown until the next puzzle unlocks, then returns its day.
+fn wait_for_unlock(clock: &impl Clock) -> Day {
+    let (day, unlock) = match Day::next_unlock_with(clock, get_year()) {
+        Ok(next) => next,
+        Err(e) => {
+            eprintln!("`today` command can't wait for the next puzzle: {e}. Please use `scaffold` with a specific day.");
+            process::exit(1);
+        }
+    };
+
+    loop {
+        let remaining = (unlock - clock.now().fixed_offset()).num_seconds();
+        if remaining <= 0 {
+            break;
+        }
+        let remaining = remaining.unsigned_abs();
+
+        print!("\r⏳ Day {day} unlocks in {} ", format_seconds(remaining));
+        let _ = stdout().flush();
+        thread::sleep(Duration::from_secs(1).min(Duration::from_secs(remaining)));
+    }
+
+    println!("\r🎄 Day {day} is unlocked!{}", " ".repeat(24));
+    day
+}
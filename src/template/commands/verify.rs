@@ -0,0 +1,157 @@
+use std::process;
+
+use crate::template::answers::{Answers, Verification};
+use crate::template::input::InputSource;
+use crate::template::report::Report;
+use crate::template::run_multi::{run_day_silent, DayOutcome};
+use crate::template::runner::RunnerConfig;
+use crate::template::{all_days, Day, Part, Registry, ANSI_BOLD, ANSI_RESET};
+
+/// Re-runs solutions and checks their answers against the accepted ones in `data/answers`,
+/// or against the ones that are expected for another input source, e.g. the examples.
+/// If `store` is set, the current answers are recorded as accepted instead, which is only
+/// possible for the real inputs.
+/// Exits non-zero if any answer does not match, or if the input of a day with expected answers is
+/// missing. Configured reports are written before exiting.
+pub fn handle(solutions: &Registry, days: &[Day], store: bool, config: &RunnerConfig) {
+    if store {
+        if let Err(e) = check_store(&config.input) {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+
+    let days: Vec<Day> = if days.is_empty() {
+        // without explicit days, only check days that have expected answers.
+        all_days()
+            .filter(|day| store || !Answers::read_for_input(*day, &config.input).is_empty())
+            .collect()
+    } else {
+        days.to_vec()
+    };
+
+    let mut mismatches = 0;
+    let mut missing_inputs = 0;
+    let mut report = Report::default();
+
+    for (index, day) in days.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let results = match run_day_silent(solutions, *day, config) {
+            DayOutcome::NotSolved => {
+                println!("Not solved.");
+                continue;
+            }
+            DayOutcome::MissingInput(e) => {
+                eprintln!("Could not open input file: {e}");
+
+                // NOTE: a day with expected answers can't pass without its input, e.g. on a fresh checkout.
+                let answers = Answers::read_for_input(*day, &config.input);
+                if !answers.is_empty() {
+                    missing_inputs += 1;
+                    for part in [Part::One, Part::Two] {
+                        if answers.get(part).is_some() {
+                            report.add_missing_input(*day, part, &e.to_string());
+                        }
+                    }
+                }
+                continue;
+            }
+            DayOutcome::Ran(results) => results,
+        };
+
+        if store {
+            let mut answers = Answers::read_from_file(*day);
+            for result in &results {
+                if let Some(answer) = &result.answer {
+                    println!("Part {}: recorded {answer}", result.part);
+                    answers.set(result.part, answer.to_string());
+                }
+            }
+            if let Err(e) = answers.store_file(*day) {
+                eprintln!("Failed to store answers: {e}");
+                process::exit(1);
+            }
+            continue;
+        }
+
+        let answers = Answers::read_for_input(*day, &config.input);
+
+        for result in &results {
+            let part = result.part;
+            let verification = answers.verify(result);
+            report.add(result, &verification);
+
+            match verification {
+                Verification::Match => println!("Part {part}: ✔"),
+                Verification::Unknown => println!("Part {part}: no accepted answer"),
+                Verification::Mismatch { expected, actual } => {
+                    mismatches += 1;
+                    let actual = match (actual, result.status.describe_failure()) {
+                        (Some(actual), _) => actual,
+                        (None, Some(failure)) => failure,
+                        (None, None) => "no answer".into(),
+                    };
+                    println!("Part {part}: ✖ expected {expected}, got {actual}");
+                }
+            }
+        }
+    }
+
+    if !store && !report.write_all(&config.reports) {
+        process::exit(1);
+    }
+
+    if mismatches > 0 || missing_inputs > 0 {
+        println!();
+    }
+    if missing_inputs > 0 {
+        println!("{ANSI_BOLD}{missing_inputs} day(s) with expected answers are missing their input.{ANSI_RESET}");
+    }
+    if mismatches > 0 {
+        println!("{ANSI_BOLD}{mismatches} answer(s) do not match.{ANSI_RESET}");
+    }
+    if mismatches > 0 || missing_inputs > 0 {
+        process::exit(1);
+    }
+}
+
+/// Only answers for the real inputs can be stored as accepted, answers for other inputs would
+/// overwrite the accepted ones in `data/answers`.
+fn check_store(input: &InputSource) -> Result<(), String> {
+    if *input == InputSource::default() {
+        Ok(())
+    } else {
+        Err("`--store` only records the answers of the real inputs, \
+            it can't be combined with `--input`, `--stdin` or `--input-set`."
+            .into())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::check_store;
+    use crate::template::input::InputSource;
+
+    #[test]
+    fn stores_only_real_inputs() {
+        assert_eq!(check_store(&InputSource::default()).is_ok(), true);
+        assert_eq!(
+            check_store(&InputSource::Set("examples".into())).is_err(),
+            true
+        );
+        assert_eq!(check_store(&InputSource::Stdin).is_err(), true);
+        assert_eq!(
+            check_store(&InputSource::File(PathBuf::from("other.txt"))).is_err(),
+            true
+        );
+    }
+}
@@ -3,7 +3,11 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use crate::template::aoc_cli::get_year;
+#[cfg(feature = "today")]
+use crate::template::clock::{Clock, SystemClock};
+#[cfg(feature = "today")]
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
@@ -14,7 +18,7 @@ const SERVER_UTC_OFFSET: i32 = -5;
 /// This value displays as a two digit number.
 ///
 /// ```
-/// # use advent_of_code::Day;
+/// # use advent_of_code::template::Day;
 /// let day = Day::new(8).unwrap();
 /// assert_eq!(day.to_string(), "08")
 /// ```
@@ -45,14 +49,113 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
-    pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
+    /// Returns the current day if it's between the 1st and the 25th of december of the year
+    /// configured with `AOC_YEAR`, or of any year if none is configured.
+    pub fn today() -> Result<Self, TodayError> {
+        Self::today_with(&SystemClock, get_year())
+    }
+
+    /// Like [`Day::today`], but reads the time from `clock` and compares against `year`.
+    pub fn today_with(clock: &impl Clock, year: Option<u16>) -> Result<Self, TodayError> {
+        let now = server_time(clock);
+        check_year(now.year(), year)?;
+
+        if now.month() == 12 && now.day() <= 25 {
+            Self::new(u8::try_from(now.day()).map_err(|_| TodayError::OutsideAdvent)?)
+                .ok_or(TodayError::OutsideAdvent)
         } else {
-            None
+            Err(TodayError::OutsideAdvent)
+        }
+    }
+
+    /// Returns the next day to unlock and when it unlocks, i.e. at midnight in the server's timezone.
+    /// After the 25th, this is the 1st of december of the next year.
+    pub fn next_unlock() -> Result<(Self, DateTime<FixedOffset>), TodayError> {
+        Self::next_unlock_with(&SystemClock, get_year())
+    }
+
+    /// Like [`Day::next_unlock`], but reads the time from `clock` and compares against `year`.
+    pub fn next_unlock_with(
+        clock: &impl Clock,
+        year: Option<u16>,
+    ) -> Result<(Self, DateTime<FixedOffset>), TodayError> {
+        let now = server_time(clock);
+
+        let (event, day) = match (now.month(), now.day()) {
+            (12, day) if day < 25 => (now.year(), day + 1),
+            (12, _) => (now.year() + 1, 1),
+            _ => (now.year(), 1),
+        };
+
+        check_year(event, year)?;
+
+        let unlock = now
+            .timezone()
+            .with_ymd_and_hms(event, 12, day, 0, 0, 0)
+            .single()
+            .ok_or(TodayError::OutsideAdvent)?;
+
+        let day = u8::try_from(day)
+            .ok()
+            .and_then(Self::new)
+            .ok_or(TodayError::OutsideAdvent)?;
+
+        Ok((day, unlock))
+    }
+}
+
+#[cfg(feature = "today")]
+fn server_time(clock: &impl Clock) -> DateTime<FixedOffset> {
+    let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)
+        .expect("SERVER_UTC_OFFSET is a valid offset");
+    clock.now().with_timezone(&offset)
+}
+
+#[cfg(feature = "today")]
+fn check_year(current: i32, configured: Option<u16>) -> Result<(), TodayError> {
+    match configured {
+        Some(configured) if i32::from(configured) != current => Err(TodayError::OtherYear {
+            configured,
+            current,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// An error which can be returned when determining the current [`Day`].
+#[cfg(feature = "today")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodayError {
+    /// The current date is not between the 1st and the 25th of december.
+    OutsideAdvent,
+    /// `AOC_YEAR` is set to another year than the current event.
+    OtherYear { configured: u16, current: i32 },
+}
+
+#[cfg(feature = "today")]
+impl Error for TodayError {}
+
+#[cfg(feature = "today")]
+impl Display for TodayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TodayError::OutsideAdvent => {
+                f.write_str("no puzzle is unlocked outside of the 1st to the 25th of december")
+            }
+            TodayError::OtherYear {
+                configured,
+                current,
+            } if i32::from(*configured) < *current => write!(
+                f,
+                "`AOC_YEAR` is set to the past event {configured}, but it is {current}"
+            ),
+            TodayError::OtherYear {
+                configured,
+                current,
+            } => write!(
+                f,
+                "`AOC_YEAR` is set to {configured}, but the next event is {current}"
+            ),
         }
     }
 }
@@ -100,6 +203,57 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
+/// An inclusive range of days, parsed from a single day like `5` or a range like `1-25`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayRange {
+    pub start: Day,
+    pub end: Day,
+}
+
+impl DayRange {
+    /// Iterates the days of the range in order.
+    pub fn days(self) -> impl Iterator<Item = Day> {
+        all_days().filter(move |day| *day >= self.start && *day <= self.end)
+    }
+
+    /// Returns the day if the range contains a single day.
+    pub fn single(self) -> Option<Day> {
+        (self.start == self.end).then_some(self.start)
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = DayRangeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s.split_once('-').unwrap_or((s, s));
+        let start: Day = start.trim().parse().map_err(|_| DayRangeFromStrError)?;
+        let end: Day = end.trim().parse().map_err(|_| DayRangeFromStrError)?;
+
+        if start > end {
+            return Err(DayRangeFromStrError);
+        }
+
+        Ok(Self { start, end })
+    }
+}
+
+/// An error which can be returned when parsing a [`DayRange`].
+#[derive(Debug)]
+pub struct DayRangeFromStrError;
+
+impl Error for DayRangeFromStrError {}
+
+impl Display for DayRangeFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "expecting a day number between 1 and 25, or an ascending range of days like `1-25`",
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// An iterator that yields every day of advent from the 1st to the 25th.
 pub fn all_days() -> AllDays {
     AllDays::new()
@@ -154,7 +308,23 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DayRange};
+
+    #[test]
+    fn parses_day_ranges() {
+        let range: DayRange = "3-5".parse().unwrap();
+        assert_eq!(range.days().collect::<Vec<_>>(), [Day(3), Day(4), Day(5)]);
+        assert_eq!(range.single(), None);
+
+        let range: DayRange = "7".parse().unwrap();
+        assert_eq!(range.days().collect::<Vec<_>>(), [Day(7)]);
+        assert_eq!(range.single(), Some(Day(7)));
+
+        assert!("5-3".parse::<DayRange>().is_err());
+        assert!("0-3".parse::<DayRange>().is_err());
+        assert!("1-26".parse::<DayRange>().is_err());
+        assert!("1-".parse::<DayRange>().is_err());
+    }
 
     #[test]
     fn all_days_iterator() {
@@ -187,6 +357,84 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[cfg(feature = "today")]
+    mod today {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        use crate::template::day::{Day, TodayError};
+
+        fn at(month: u32, day: u32, hour: u32, min: u32, sec: u32) -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(2024, month, day, hour, min, sec)
+                .unwrap()
+        }
+
+        #[test]
+        fn today_before_advent() {
+            let clock = at(11, 30, 12, 0, 0);
+            assert_eq!(
+                Day::today_with(&clock, None),
+                Err(TodayError::OutsideAdvent)
+            );
+
+            let (day, unlock) = Day::next_unlock_with(&clock, None).unwrap();
+            assert_eq!(day, Day(1));
+            assert_eq!(unlock, at(12, 1, 5, 0, 0));
+        }
+
+        #[test]
+        fn today_at_unlock() {
+            let clock = at(12, 1, 4, 59, 59);
+            assert_eq!(
+                Day::today_with(&clock, None),
+                Err(TodayError::OutsideAdvent)
+            );
+            assert_eq!(Day::next_unlock_with(&clock, None).unwrap().0, Day(1));
+
+            let clock = at(12, 1, 5, 0, 0);
+            assert_eq!(Day::today_with(&clock, None), Ok(Day(1)));
+
+            let (day, unlock) = Day::next_unlock_with(&clock, None).unwrap();
+            assert_eq!(day, Day(2));
+            assert_eq!(unlock, at(12, 2, 5, 0, 0));
+        }
+
+        #[test]
+        fn today_at_end_of_advent() {
+            let clock = at(12, 25, 5, 0, 0);
+            assert_eq!(Day::today_with(&clock, Some(2024)), Ok(Day(25)));
+            assert_eq!(
+                Day::next_unlock_with(&clock, Some(2024)),
+                Err(TodayError::OtherYear {
+                    configured: 2024,
+                    current: 2025
+                })
+            );
+
+            let clock = at(12, 26, 5, 0, 0);
+            assert_eq!(
+                Day::today_with(&clock, None),
+                Err(TodayError::OutsideAdvent)
+            );
+
+            let (day, unlock) = Day::next_unlock_with(&clock, None).unwrap();
+            assert_eq!(day, Day(1));
+            assert_eq!(unlock, Utc.with_ymd_and_hms(2025, 12, 1, 5, 0, 0).unwrap());
+        }
+
+        #[test]
+        fn today_of_past_year() {
+            let clock = at(12, 5, 12, 0, 0);
+            assert_eq!(Day::today_with(&clock, Some(2024)), Ok(Day(5)));
+            assert_eq!(
+                Day::today_with(&clock, Some(2023)),
+                Err(TodayError::OtherYear {
+                    configured: 2023,
+                    current: 2024
+                })
+            );
+        }
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,65 @@
+/// Toggles the output of [`aoc_debug!`](crate::aoc_debug), which is disabled by default.
+/// The runner enables it for `--verbose` runs, except while benchmarking. The setting is kept per
+/// thread, so parts that run in parallel don't affect each other.
+use std::cell::Cell;
+
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether [`aoc_debug!`](crate::aoc_debug) currently prints on this thread.
+#[inline]
+pub fn is_enabled() -> bool {
+    ENABLED.with(Cell::get)
+}
+
+/// Enables or disables debug output for the current thread. Returns the previous setting.
+pub fn set_enabled(enabled: bool) -> bool {
+    ENABLED.with(|cell| cell.replace(enabled))
+}
+
+/// Prints to stderr like `eprintln!`, but only if the solution runs with `--verbose`.
+/// In release builds, which are used for benchmarking, it compiles to nothing. Otherwise, e.g. in
+/// tests or after the first run of a part, its arguments are not evaluated.
+///
+/// ```
+/// # use advent_of_code::aoc_debug;
+/// let grid = vec!["#..", ".#."];
+/// aoc_debug!("grid after 10 steps:\n{}", grid.join("\n"));
+/// ```
+#[macro_export]
+macro_rules! aoc_debug {
+    ($($arg:tt)*) => {
+        // NOTE: `cfg!` is a constant, so the whole statement is removed from release builds.
+        if cfg!(debug_assertions) && $crate::template::debug::is_enabled() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::thread;
+
+    use super::{is_enabled, set_enabled};
+
+    #[test]
+    fn skips_arguments_when_disabled() {
+        let mut evaluated = false;
+        crate::aoc_debug!("{}", {
+            evaluated = true;
+            "expensive"
+        });
+        assert!(!evaluated);
+    }
+
+    #[test]
+    fn is_enabled_per_thread() {
+        let previous = set_enabled(true);
+        assert!(!thread::spawn(is_enabled).join().unwrap());
+        assert!(is_enabled());
+        set_enabled(previous);
+    }
+}
@@ -0,0 +1,130 @@
+/// Sources a solution can read its puzzle input from.
+use std::{
+    env,
+    error::Error,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::template::{year, Day};
+
+/// The folder in `./data` that solutions read from by default.
+const DEFAULT_INPUT_SET: &str = "inputs";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// An explicit file, e.g. a colleague's input or a hand-made edge case.
+    File(PathBuf),
+    /// Everything written to standard input.
+    Stdin,
+    /// A folder in `./data` containing one `DD.txt` file per day, e.g. `inputs` or `examples`.
+    Set(String),
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        Self::Set(DEFAULT_INPUT_SET.into())
+    }
+}
+
+impl InputSource {
+    /// Reads the input source from command-line arguments:
+    /// `--input <path>`, `--stdin` or `--input-set <folder>`. At most one of them may be passed.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn Error>> {
+        let file: Option<PathBuf> = args.opt_value_from_str("--input")?;
+        let stdin = args.contains("--stdin");
+        let set: Option<String> = args.opt_value_from_str("--input-set")?;
+
+        match (file, stdin, set) {
+            (None, false, None) => Ok(Self::default()),
+            (Some(path), false, None) => Ok(Self::File(path)),
+            (None, true, None) => Ok(Self::Stdin),
+            (None, false, Some(set)) => Ok(Self::Set(set)),
+            _ => Err("only one of `--input`, `--stdin` and `--input-set` can be passed".into()),
+        }
+    }
+
+    /// Reads the input of `day` from this source.
+    pub fn read(&self, day: Day) -> io::Result<String> {
+        match self {
+            Self::File(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Self::Set(folder) => {
+                let filepath = env::current_dir()?
+                    .join(year::data_dir())
+                    .join(folder)
+                    .join(format!("{day}.txt"));
+                fs::read_to_string(filepath)
+            }
+        }
+    }
+
+    /// The command-line arguments that select this source, used to forward it to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::File(path) => vec!["--input".into(), path.display().to_string()],
+            Self::Stdin => vec!["--stdin".into()],
+            Self::Set(set) if set == DEFAULT_INPUT_SET => vec![],
+            Self::Set(set) => vec!["--input-set".into(), set.clone()],
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{error::Error, path::PathBuf};
+
+    use super::InputSource;
+
+    fn parse(args: &[&str]) -> Result<InputSource, Box<dyn Error>> {
+        let args = args.iter().map(Into::into).collect();
+        InputSource::from_args(&mut pico_args::Arguments::from_vec(args))
+    }
+
+    #[test]
+    fn defaults_to_inputs() {
+        assert_eq!(parse(&[]).unwrap(), InputSource::Set("inputs".into()));
+        assert_eq!(InputSource::default().to_args().is_empty(), true);
+    }
+
+    #[test]
+    fn parses_sources() {
+        assert_eq!(
+            parse(&["--input", "edge.txt"]).unwrap(),
+            InputSource::File(PathBuf::from("edge.txt"))
+        );
+        assert_eq!(parse(&["--stdin"]).unwrap(), InputSource::Stdin);
+        assert_eq!(
+            parse(&["--input-set", "examples"]).unwrap(),
+            InputSource::Set("examples".into())
+        );
+    }
+
+    #[test]
+    fn rejects_multiple_sources() {
+        assert_eq!(
+            parse(&["--stdin", "--input-set", "examples"]).is_err(),
+            true
+        );
+    }
+
+    #[test]
+    fn round_trips_args() {
+        for source in [
+            InputSource::File(PathBuf::from("edge.txt")),
+            InputSource::Stdin,
+            InputSource::Set("colleague".into()),
+        ] {
+            let args = source.to_args().into_iter().map(Into::into).collect();
+            let parsed = InputSource::from_args(&mut pico_args::Arguments::from_vec(args));
+            assert_eq!(parsed.unwrap(), source);
+        }
+    }
+}
@@ -0,0 +1,294 @@
+/// Model of a private leaderboard, parsed from its JSON export on the website.
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+use tinyjson::JsonValue;
+
+use crate::template::{all_days, Day, Part};
+
+/// A private leaderboard of a single event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    /// The year of the event, e.g. `2024`.
+    pub event: String,
+    pub members: Vec<Member>,
+}
+
+/// A member of a leaderboard and the stars they collected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users.
+    pub name: Option<String>,
+    pub stars: u32,
+    /// The local score as exported, see [`Leaderboard::local_scores`] for the recomputed one.
+    pub local_score: u64,
+    /// Timestamp of the latest star, used to break ties.
+    pub last_star_ts: u64,
+    pub completions: BTreeMap<Day, DayCompletion>,
+}
+
+/// The stars of a member for a single day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayCompletion {
+    pub part_1: Option<Star>,
+    pub part_2: Option<Star>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Star {
+    /// Unix timestamp of when the star was collected.
+    pub timestamp: u64,
+    /// Global order in which stars were collected, breaks ties between equal timestamps.
+    pub star_index: u64,
+}
+
+impl Leaderboard {
+    /// Recomputes the local score of every member by their id: for `n` members, the first member
+    /// to collect a star gets `n` points, the second `n - 1` and so on.
+    pub fn local_scores(&self) -> HashMap<u64, u64> {
+        let mut scores: HashMap<u64, u64> = self.members.iter().map(|m| (m.id, 0)).collect();
+        let member_count = self.members.len() as u64;
+
+        for day in all_days() {
+            for part in [Part::One, Part::Two] {
+                let mut stars: Vec<(Star, u64)> = self
+                    .members
+                    .iter()
+                    .filter_map(|member| Some((member.star(day, part)?, member.id)))
+                    .collect();
+
+                stars.sort_unstable_by_key(|(star, _)| (star.timestamp, star.star_index));
+
+                for (rank, (_, id)) in stars.iter().enumerate() {
+                    *scores.entry(*id).or_default() += member_count - rank as u64;
+                }
+            }
+        }
+
+        scores
+    }
+
+    /// Unix timestamp of when the puzzle of `day` was unlocked, i.e. midnight EST (UTC-5).
+    /// Returns `None` if the event is not a year.
+    pub fn unlock_timestamp(&self, day: Day) -> Option<u64> {
+        let year: i64 = self.event.parse().ok()?;
+        let days = days_from_civil(year, 12, i64::from(day.into_inner()));
+        u64::try_from(days * 86_400 + 5 * 3_600).ok()
+    }
+}
+
+impl Member {
+    /// The name shown on the website, anonymous users are shown with their id.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn star(&self, day: Day, part: Part) -> Option<Star> {
+        let completion = self.completions.get(&day)?;
+        match part {
+            Part::One => completion.part_1,
+            Part::Two => completion.part_2,
+        }
+    }
+
+    /// Seconds between collecting the first and the second star of `day`.
+    pub fn part_delta(&self, day: Day) -> Option<u64> {
+        let part_1 = self.star(day, Part::One)?;
+        let part_2 = self.star(day, Part::Two)?;
+        part_2.timestamp.checked_sub(part_1.timestamp)
+    }
+}
+
+/// Days since the unix epoch of a date in the proleptic gregorian calendar.
+/// see: <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<&str> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON file."))?;
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: the event is a string in exports, but accept numbers as well.
+        let event = match json.get("event") {
+            Some(JsonValue::String(event)) => event.clone(),
+            Some(JsonValue::Number(event)) => event.to_string(),
+            _ => return Err("expected JSON document to have key `event`.".into()),
+        };
+
+        let mut members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `json.members` to be an object.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        members.sort_unstable_by_key(|member| member.id);
+
+        Ok(Leaderboard { event, members })
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn get_number(json: &HashMap<String, JsonValue>, key: &str) -> Option<u64> {
+    json.get(key)
+        .and_then(|v| v.get::<f64>())
+        .map(|x| *x as u64)
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected member to be an object.")?;
+
+        let id = get_number(json, "id").ok_or("expected member.id to be a number.")?;
+
+        let completions = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or(format!(
+                "expected member.completion_day_level of member {id} to be an object."
+            ))?
+            .iter()
+            .map(|(day, parts)| {
+                let day = Day::from_str(day).map_err(|_| format!("invalid day `{day}`."))?;
+                let star = |part: &str| -> Result<Option<Star>, String> {
+                    match parts
+                        .get::<HashMap<String, JsonValue>>()
+                        .and_then(|p| p.get(part))
+                    {
+                        None => Ok(None),
+                        Some(star) => {
+                            let star = star
+                                .get::<HashMap<String, JsonValue>>()
+                                .ok_or(format!("expected star of day {day} to be an object."))?;
+                            Ok(Some(Star {
+                                timestamp: get_number(star, "get_star_ts").ok_or(format!(
+                                    "expected get_star_ts of day {day} to be a number."
+                                ))?,
+                                star_index: get_number(star, "star_index").unwrap_or_default(),
+                            }))
+                        }
+                    }
+                };
+                Ok((
+                    day,
+                    DayCompletion {
+                        part_1: star("1")?,
+                        part_2: star("2")?,
+                    },
+                ))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Member {
+            id,
+            name: json.get("name").and_then(|v| v.get::<String>()).cloned(),
+            stars: u32::try_from(get_number(json, "stars").unwrap_or_default()).unwrap_or_default(),
+            local_score: get_number(json, "local_score").unwrap_or_default(),
+            last_star_ts: get_number(json, "last_star_ts").unwrap_or_default(),
+            completions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Leaderboard;
+    use crate::{day, template::Part};
+
+    const EXPORT: &str = r#"{
+        "event": "2024",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 7, "global_score": 0, "last_star_ts": 1733116000,
+                "completion_day_level": {
+                    "1": {
+                        "1": { "get_star_ts": 1733029500, "star_index": 10 },
+                        "2": { "get_star_ts": 1733029800, "star_index": 12 }
+                    },
+                    "2": { "1": { "get_star_ts": 1733116000, "star_index": 20 } }
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 5, "global_score": 0, "last_star_ts": 1733029700,
+                "completion_day_level": {
+                    "1": {
+                        "1": { "get_star_ts": 1733029400, "star_index": 9 },
+                        "2": { "get_star_ts": 1733029700, "star_index": 11 }
+                    }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn parses_exports() {
+        let leaderboard = Leaderboard::try_from(EXPORT).unwrap();
+        assert_eq!(leaderboard.event, "2024");
+        assert_eq!(leaderboard.members.len(), 2);
+
+        let alice = &leaderboard.members[0];
+        assert_eq!(alice.display_name(), "Alice");
+        assert_eq!(alice.stars, 3);
+        assert_eq!(alice.star(day!(2), Part::Two), None);
+        assert_eq!(
+            alice.star(day!(2), Part::One).unwrap().timestamp,
+            1733116000
+        );
+
+        assert_eq!(leaderboard.members[1].display_name(), "(anonymous user #2)");
+    }
+
+    #[test]
+    fn recomputes_local_scores() {
+        let leaderboard = Leaderboard::try_from(EXPORT).unwrap();
+        let scores = leaderboard.local_scores();
+        // day 1: anonymous user is first on both parts, alice is alone on day 2.
+        assert_eq!(scores[&1], 1 + 1 + 2);
+        assert_eq!(scores[&2], 2 + 2);
+    }
+
+    #[test]
+    fn computes_deltas() {
+        let leaderboard = Leaderboard::try_from(EXPORT).unwrap();
+        assert_eq!(leaderboard.members[0].part_delta(day!(1)), Some(300));
+        assert_eq!(leaderboard.members[0].part_delta(day!(2)), None);
+    }
+
+    #[test]
+    fn computes_unlock_timestamps() {
+        let leaderboard = Leaderboard::try_from(EXPORT).unwrap();
+        assert_eq!(leaderboard.unlock_timestamp(day!(1)), Some(1733029200));
+        assert_eq!(leaderboard.unlock_timestamp(day!(25)), Some(1735102800));
+    }
+
+    #[test]
+    fn rejects_invalid_exports() {
+        assert!(Leaderboard::try_from("{}").is_err());
+        assert!(Leaderboard::try_from(r#"{ "event": "2024", "members": [] }"#).is_err());
+    }
+}
@@ -1,27 +1,83 @@
-use std::{env, fs};
+use std::{env, fs, io, path::PathBuf};
 
+pub mod alloc;
 pub mod aoc_cli;
+pub mod bench;
+#[cfg(feature = "today")]
+pub mod clock;
 pub mod commands;
+pub mod debug;
+pub mod input;
+pub mod parse;
+pub mod report;
 pub mod runner;
+pub mod year;
 
 pub use day::*;
+pub use part::*;
+pub use solution::*;
 
+mod answers;
 mod day;
+mod leaderboard;
+mod part;
 mod readme_benchmarks;
+mod rss;
 mod run_multi;
+mod solution;
 mod timings;
+mod watch;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[cfg(all(feature = "count-allocs", not(feature = "dhat-heap")))]
+#[global_allocator]
+static ALLOC: alloc::CountingAlloc = alloc::CountingAlloc;
+
 /// Helper function that reads a text file to a string.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    try_read_file(folder, day).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning an error if it can't be read.
+pub fn try_read_file(folder: &str, day: Day) -> io::Result<String> {
+    input::InputSource::Set(folder.into()).read(day)
+}
+
+/// The source file of a day's solution: its library module in `src/solutions` if it exists,
+/// otherwise its binary in `src/bin`.
+#[must_use]
+pub fn solution_path(day: Day) -> PathBuf {
+    let module_path = PathBuf::from(format!("src/solutions/day{day}.rs"));
+    if module_path.exists() {
+        module_path
+    } else {
+        PathBuf::from(format!("src/bin/{day}.rs"))
+    }
+}
+
+/// Formats a number of seconds as `hh:mm:ss`, prefixed with the days if there are any,
+/// e.g. `00:12:34` or `2d 01:00:00`.
+#[must_use]
+pub fn format_seconds(seconds: u64) -> String {
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        (seconds / 3_600) % 24,
+        (seconds / 60) % 60,
+        seconds % 60
+    );
+
+    match seconds / 86_400 {
+        0 => time,
+        days => format!("{days}d {time}"),
+    }
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
@@ -37,32 +93,77 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Also creates the static `SOLUTION`, which registers the day with the in-process runner,
+/// and a public `main` function, so a library module in `src/solutions` can be run by a thin binary.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, [part_one, One] [part_two, Two]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, [part_one, One]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, [part_two, Two]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, $( [$func:expr, $part:ident] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// The current day's entry in the solution registry.
+        pub static SOLUTION: &dyn $crate::template::Solution = &{
+            struct DaySolution;
+
+            impl $crate::template::Solution for DaySolution {
+                fn day(&self) -> $crate::template::Day {
+                    DAY
+                }
+
+                fn parts(&self) -> &'static [$crate::template::Part] {
+                    &[$( $crate::template::Part::$part ),*]
+                }
+
+                fn run_part(
+                    &self,
+                    part: $crate::template::Part,
+                    input: &str,
+                    config: &$crate::template::runner::RunnerConfig,
+                ) -> Option<$crate::template::runner::PartResult> {
+                    use $crate::template::runner::*;
+                    $(
+                        if part == $crate::template::Part::$part {
+                            return Some(run_part($func, input, DAY, part, config));
+                        }
+                    )*
+                    None
+                }
+            }
 
-        fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            DaySolution
+        };
+
+        pub fn main() {
+            let config = $crate::template::runner::RunnerConfig::from_env();
+            if let Err(e) = $crate::template::runner::run_solution(SOLUTION, &config) {
+                eprintln!("Could not read input: {e}");
+                std::process::exit(1);
+            }
         }
     };
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_seconds;
+
+    #[test]
+    fn formats_seconds() {
+        assert_eq!(format_seconds(754), "00:12:34");
+        assert_eq!(format_seconds(2 * 86_400 + 3_600), "2d 01:00:00");
+    }
+}
@@ -0,0 +1,277 @@
+/// Helpers for parsing puzzle inputs line by line, so parse errors know where they happened.
+use std::error::Error;
+use std::fmt::Display;
+use std::str::{FromStr, Split, SplitWhitespace};
+
+/// An error in a puzzle input, pointing at the offending text.
+///
+/// # Display
+/// Renders a caret-annotated snippet of the offending line:
+///
+/// ```text
+/// line 3, column 5: expected a number, found `ab`
+///   |
+/// 3 | 12  ab
+///   |     ^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, in characters.
+    pub column: usize,
+    /// The offending text. Empty if the line ended too early.
+    pub found: String,
+    /// Description of what was expected instead, e.g. `a number`.
+    pub expected: String,
+    /// The full offending line.
+    pub source_line: String,
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let found = match (self.found.as_str(), self.source_line.as_str()) {
+            ("", "") => "an empty line".to_string(),
+            ("", _) => "end of line".to_string(),
+            (found, _) => format!("`{found}`"),
+        };
+
+        writeln!(
+            f,
+            "line {}, column {}: expected {}, found {found}",
+            self.line, self.column, self.expected
+        )?;
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding = " ".repeat(self.column - 1);
+        let carets = "^".repeat(self.found.chars().count().max(1));
+
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{gutter} | {padding}{carets}")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A line of a puzzle input with its 1-based line number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Iterates the lines of an input, numbering them for error reporting.
+///
+/// ```
+/// # use advent_of_code::template::parse::{lines, ParseError};
+/// fn parse(input: &str) -> Result<Vec<(u32, u32)>, ParseError> {
+///     lines(input)
+///         .map(|line| {
+///             let mut fields = line.split_whitespace();
+///             let pair = (fields.parse_next("a number")?, fields.parse_next("a number")?);
+///             fields.finish()?;
+///             Ok(pair)
+///         })
+///         .collect()
+/// }
+///
+/// assert_eq!(parse("1 2\n3 4"), Ok(vec![(1, 2), (3, 4)]));
+/// assert_eq!(parse("1 2\n3 x").unwrap_err().column, 3);
+/// ```
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+/// Groups the lines of an input into blocks that are separated by blank lines.
+/// Lines keep their number in the whole input.
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+        } else {
+            block.push(line);
+        }
+    }
+
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+
+    blocks
+}
+
+impl<'a> Line<'a> {
+    /// An error pointing at the whole line.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.text.trim(), expected)
+    }
+
+    /// An error pointing at `field`, which has to be a slice of the line's text,
+    /// e.g. a field returned by [`Line::split`] or a regex capture.
+    /// Other strings point at the end of the line.
+    pub fn error_at(&self, field: &str, expected: impl Into<String>) -> ParseError {
+        let start = field.as_ptr() as usize;
+        let line_start = self.text.as_ptr() as usize;
+
+        let offset = if (line_start..=line_start + self.text.len()).contains(&start)
+            && self.text.is_char_boundary(start - line_start)
+        {
+            start - line_start
+        } else {
+            self.text.len()
+        };
+
+        ParseError {
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            found: self.text[offset..]
+                .get(..field.len())
+                .unwrap_or_default()
+                .to_string(),
+            expected: expected.into(),
+            source_line: self.text.to_string(),
+        }
+    }
+
+    /// Parses the whole line, ignoring surrounding whitespace.
+    pub fn parse<T: FromStr>(&self, expected: impl Into<String>) -> Result<T, ParseError> {
+        self.parse_field(self.text.trim(), expected)
+    }
+
+    /// Parses `field`, which has to be a slice of the line's text, see [`Line::error_at`].
+    pub fn parse_field<T: FromStr>(
+        &self,
+        field: &str,
+        expected: impl Into<String>,
+    ) -> Result<T, ParseError> {
+        field.parse().map_err(|_| self.error_at(field, expected))
+    }
+
+    /// Splits the line into fields separated by `pattern`.
+    pub fn split(&self, pattern: &'a str) -> Fields<'a, Split<'a, &'a str>> {
+        Fields {
+            line: *self,
+            inner: self.text.split(pattern),
+        }
+    }
+
+    /// Splits the line into fields separated by whitespace.
+    pub fn split_whitespace(&self) -> Fields<'a, SplitWhitespace<'a>> {
+        Fields {
+            line: *self,
+            inner: self.text.split_whitespace(),
+        }
+    }
+}
+
+/// The fields of a [`Line`]. Parse errors point at the offending field.
+pub struct Fields<'a, I> {
+    line: Line<'a>,
+    inner: I,
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Iterator for Fields<'a, I> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Fields<'a, I> {
+    /// Parses the next field, fails if the line has no more fields.
+    pub fn parse_next<T: FromStr>(&mut self, expected: impl Into<String>) -> Result<T, ParseError> {
+        let line = self.line;
+        match self.inner.next() {
+            Some(field) => line.parse_field(field, expected),
+            None => Err(line.error_at("", expected)),
+        }
+    }
+
+    /// Fails if the line has any fields left.
+    pub fn finish(mut self) -> Result<(), ParseError> {
+        match self.inner.next() {
+            Some(field) => Err(self.line.error_at(field, "end of line")),
+            None => Ok(()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{blocks, lines, ParseError};
+
+    #[test]
+    fn points_at_fields() {
+        let line = lines("1 2\n34   ab").nth(1).unwrap();
+        let mut fields = line.split("   ");
+
+        assert_eq!(fields.parse_next::<u32>("a number"), Ok(34));
+        assert_eq!(
+            fields.parse_next::<u32>("a number"),
+            Err(ParseError {
+                line: 2,
+                column: 6,
+                found: "ab".into(),
+                expected: "a number".into(),
+                source_line: "34   ab".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn points_at_missing_fields() {
+        let line = lines("12").next().unwrap();
+        let mut fields = line.split_whitespace();
+
+        assert_eq!(fields.parse_next::<u32>("a number"), Ok(12));
+        let err = fields.parse_next::<u32>("a number").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (3, ""));
+    }
+
+    #[test]
+    fn rejects_trailing_fields() {
+        let line = lines("1 2").next().unwrap();
+        let mut fields = line.split_whitespace();
+
+        fields.next();
+        assert_eq!(fields.finish().unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn renders_snippets() {
+        let line = lines("1   2\n12   ab").nth(1).unwrap();
+        let err = line.error_at(&line.text[5..], "a number");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a number, found `ab`\n  |\n2 | 12   ab\n  |      ^^"
+        );
+
+        let err = lines("\n").next().unwrap().error("a number");
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected a number, found an empty line\n  |\n1 | \n  | ^"
+        );
+    }
+
+    #[test]
+    fn groups_blocks() {
+        let blocks = blocks("a\nb\n\n\nc\n");
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1][0].number, 5);
+        assert_eq!(blocks[1][0].text, "c");
+    }
+}
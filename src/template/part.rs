@@ -0,0 +1,277 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A part of a day's puzzle.
+///
+/// # Display
+/// This value displays as its number.
+///
+/// ```
+/// # use advent_of_code::template::Part;
+/// assert_eq!(Part::Two.to_string(), "2")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Creates a [`Part`] from its number, returns [`None`] if it's neither 1 nor 2.
+    pub fn new(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    /// Converts the [`Part`] into its number.
+    pub fn into_inner(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.into_inner())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Part {
+    type Err = PartFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let part = s.parse().map_err(|_| PartFromStrError)?;
+        Self::new(part).ok_or(PartFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Part`].
+#[derive(Debug)]
+pub struct PartFromStrError;
+
+impl Error for PartFromStrError {}
+
+impl Display for PartFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a part number of 1 or 2")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The answer of a solution part, normalized for display and submission:
+/// ANSI escape sequences are removed, as well as surrounding and trailing whitespace.
+/// The remaining text is kept as is, e.g. answers with leading zeros are not integers.
+///
+/// ```
+/// # use advent_of_code::template::Answer;
+/// assert_eq!(Answer::new(" 42\n"), Answer::Integer(42));
+/// assert_eq!(Answer::new("0952"), Answer::Text("0952".into()));
+/// assert_eq!(Answer::new("\x1b[1mabc\x1b[0m"), Answer::Text("abc".into()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// An answer spanning multiple lines, e.g. a rendered grid. Can't be submitted as is.
+    Multiline(String),
+}
+
+impl Answer {
+    /// Creates a normalized [`Answer`] from a solution's result.
+    pub fn new(value: impl Display) -> Self {
+        let stripped = strip_ansi(&value.to_string());
+
+        // NOTE: leading whitespace of lines is kept, it might be part of a rendered grid.
+        let lines: Vec<&str> = stripped
+            .lines()
+            .map(str::trim_end)
+            .skip_while(|line| line.trim().is_empty())
+            .collect();
+        let len = lines.len() - lines.iter().rev().take_while(|l| l.is_empty()).count();
+
+        match &lines[..len] {
+            // NOTE: only answers that print the same as a number are integers, e.g. not `+5`.
+            [line] => match line.trim().parse::<i128>() {
+                Ok(x) if x.to_string() == line.trim() => Self::Integer(x),
+                _ => Self::Text(line.trim().into()),
+            },
+            [] => Self::Text(String::new()),
+            lines => Self::Multiline(lines.join("\n")),
+        }
+    }
+
+    /// Whether the answer spans multiple lines.
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Self::Multiline(_))
+    }
+
+    /// The value that is submitted to the website. Empty and multiline answers are refused.
+    pub fn to_submission(&self) -> Result<String, SubmitError> {
+        match self {
+            Self::Multiline(_) => Err(SubmitError::Multiline),
+            Self::Text(x) if x.trim().is_empty() => Err(SubmitError::Empty),
+            x => Ok(x.to_string()),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(x) => write!(f, "{x}"),
+            Self::Text(x) | Self::Multiline(x) => f.write_str(x),
+        }
+    }
+}
+
+/// The return value of a solution part: `Option<T>`, or `Result<T, E>` for parts that can fail,
+/// e.g. on malformed input. Errors are reported by the runner and never submitted.
+pub trait PartOutput {
+    /// The normalized answer of the part, or the rendered error if it failed.
+    fn to_answer(&self) -> Result<Option<Answer>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn to_answer(&self) -> Result<Option<Answer>, String> {
+        Ok(self.as_ref().map(Answer::new))
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    fn to_answer(&self) -> Result<Option<Answer>, String> {
+        match self {
+            Ok(x) => Ok(Some(Answer::new(x))),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Removes ANSI escape sequences of the form `ESC [ <params> <final byte>`, e.g. colors.
+fn strip_ansi(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' && chars.peek() == Some(&'[') {
+            chars.next();
+            for c in chars.by_ref() {
+                if ('\x40'..='\x7e').contains(&c) {
+                    break;
+                }
+            }
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which is returned when an answer can't be submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SubmitError {
+    /// The solution did not return an answer.
+    NoAnswer,
+    /// The solution returned an empty answer.
+    Empty,
+    /// The answer spans multiple lines and needs to be read by a human, e.g. a rendered grid.
+    Multiline,
+    /// The solution returned the contained error.
+    Failed(String),
+}
+
+impl Error for SubmitError {}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::NoAnswer => f.write_str("the solution did not return an answer"),
+            SubmitError::Empty => f.write_str("the solution returned an empty answer"),
+            SubmitError::Multiline => f.write_str(
+                "the answer spans multiple lines, read it from the output and submit it manually",
+            ),
+            SubmitError::Failed(message) => {
+                write!(f, "the solution returned an error: {message}")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, Part, PartOutput, SubmitError};
+
+    #[test]
+    fn parses_parts() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert_eq!("3".parse::<Part>().is_err(), true);
+        assert_eq!(Part::new(0), None);
+    }
+
+    #[test]
+    fn normalizes_answers() {
+        assert_eq!(Answer::new(42_u64), Answer::Integer(42));
+        assert_eq!(Answer::new(-7_i32), Answer::Integer(-7));
+        assert_eq!(Answer::new("  1,2,3 \n"), Answer::Text("1,2,3".into()));
+        assert_eq!(
+            Answer::new("\x1b[1m\x1b[31m123\x1b[0m"),
+            Answer::Integer(123)
+        );
+        assert_eq!(
+            Answer::new("\n##.  \n.##\n"),
+            Answer::Multiline("##.\n.##".into())
+        );
+    }
+
+    #[test]
+    fn keeps_answers_that_are_not_plain_integers() {
+        assert_eq!(Answer::new("0952"), Answer::Text("0952".into()));
+        assert_eq!(Answer::new("+5"), Answer::Text("+5".into()));
+        assert_eq!(Answer::new("-0"), Answer::Text("-0".into()));
+        assert_eq!(Answer::new(" -12 "), Answer::Integer(-12));
+        assert_eq!(Answer::new("0952").to_string(), "0952");
+    }
+
+    #[test]
+    fn converts_part_outputs() {
+        assert_eq!(Some(42).to_answer(), Ok(Some(Answer::Integer(42))));
+        assert_eq!(None::<u32>.to_answer(), Ok(None));
+        assert_eq!(
+            Ok::<_, String>("abc").to_answer(),
+            Ok(Some(Answer::Text("abc".into())))
+        );
+        assert_eq!(
+            Err::<u32, _>("unexpected end of input").to_answer(),
+            Err("unexpected end of input".into())
+        );
+    }
+
+    #[test]
+    fn refuses_multiline_and_empty_submissions() {
+        assert_eq!(Answer::new(42).to_submission(), Ok("42".into()));
+        assert_eq!(Answer::new("007").to_submission(), Ok("007".into()));
+        assert_eq!(
+            Answer::new("#\n#").to_submission(),
+            Err(SubmitError::Multiline)
+        );
+        assert_eq!(Answer::new("").to_submission(), Err(SubmitError::Empty));
+        assert_eq!(
+            Answer::new(" \n\t").to_submission(),
+            Err(SubmitError::Empty)
+        );
+    }
+}
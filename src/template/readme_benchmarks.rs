@@ -2,8 +2,9 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use crate::template::runner::format_bytes;
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{solution_path, year, Day};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -26,8 +27,12 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_solution(day: Day) -> String {
+    // NOTE: the readme of an archived year sits next to its solutions.
+    match year::selected() {
+        Some(_) => format!("./day{day}.rs"),
+        None => format!("./{}", solution_path(day).display()),
+    }
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -52,26 +57,43 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+/// Builds the benchmark table. If `show_memory` is set, it includes a column with the peak memory of each day.
+fn construct_table(prefix: &str, timings: Timings, total_millis: f64, show_memory: bool) -> String {
     let header = format!("{prefix} Benchmarks");
 
+    let (columns, alignments) = if show_memory {
+        (
+            "| Day | Part 1 | Part 2 | Memory |",
+            "| :---: | :---: | :---:  | :---: |",
+        )
+    } else {
+        ("| Day | Part 1 | Part 2 |", "| :---: | :---: | :---:  |")
+    };
+
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        columns.into(),
+        alignments.into(),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
+        let path = get_path_for_solution(timing.day);
+        let mut line = format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        );
+        if show_memory {
+            let memory = timing
+                .max_rss_bytes
+                .map_or_else(|| "-".into(), format_bytes);
+            line.push_str(&format!(" `{memory}` |"));
+        }
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -81,25 +103,44 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    show_memory: bool,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings, total_millis, show_memory);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+pub fn update(timings: Timings, show_memory: bool) -> Result<(), Error> {
+    let path = year::readme_path();
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings, total_millis, show_memory)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
+/// Replaces the benchmark table with an empty one, returning the table if there was one.
+/// The returned table still has its markers, so it can be updated wherever it's moved to.
+pub fn take_content(s: &mut String) -> Result<Option<String>, Error> {
+    let positions = locate_table(s)?;
+    let table = s[positions.pos_start..positions.pos_end].to_string();
+    s.replace_range(positions.pos_start..positions.pos_end, MARKER);
+
+    if table.replace(MARKER, "").trim().is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(table))
+    }
+}
+
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
+    use super::{take_content, update_content, MARKER};
     use crate::{day, template::timings::Timing, template::timings::Timings};
 
     fn get_mock_timings() -> Timings {
@@ -110,18 +151,24 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    max_rss_bytes: None,
+                    statistic: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    max_rss_bytes: None,
+                    statistic: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    max_rss_bytes: None,
+                    statistic: None,
                 },
             ],
         }
@@ -131,28 +178,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, false).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, false).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, false).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, false).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, false).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +207,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, false).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,9 +216,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/solutions/day01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/solutions/day02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/solutions/day04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +227,36 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_memory() {
+        let mut timings = get_mock_timings();
+        timings.data[0].max_rss_bytes = Some(3 * 1024 * 1024);
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0, true).unwrap();
+        assert_eq!(s.contains("| Day | Part 1 | Part 2 | Memory |"), true);
+        assert_eq!(
+            s.contains("| [Day 1](./src/solutions/day01.rs) | `10ms` | `20ms` | `3.0MiB` |"),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 2](./src/solutions/day02.rs) | `30ms` | `40ms` | `-` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn takes_benchmarks() {
+        let mut s = format!("foo\n{}\nbaz", MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, false).unwrap();
+
+        let table = take_content(&mut s).unwrap().unwrap();
+        assert_eq!(s, format!("foo\n{}\nbaz", MARKER));
+        assert_eq!(table.matches(MARKER).count(), 2);
+        assert_eq!(table.contains("## Benchmarks"), true);
+
+        assert_eq!(take_content(&mut s).unwrap(), None);
+        assert_eq!(s, format!("foo\n{}\nbaz", MARKER));
+    }
 }
@@ -0,0 +1,404 @@
+/// Test reports of solution runs for CI systems, with one test case per day and part.
+use std::error::Error;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::template::answers::Verification;
+use crate::template::runner::{PartResult, PartStatus};
+use crate::template::{Day, Part};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// JUnit XML, as consumed by most CI dashboards.
+    JUnit,
+    /// The Test Anything Protocol, version 13.
+    Tap,
+}
+
+/// A report to write after a run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReportConfig {
+    pub format: ReportFormat,
+    pub path: PathBuf,
+}
+
+impl ReportConfig {
+    /// Reads the reports to write from command-line arguments: `--junit <path>` and `--tap <path>`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Vec<Self>, Box<dyn Error>> {
+        let mut reports = vec![];
+
+        if let Some(path) = args.opt_value_from_str("--junit")? {
+            reports.push(Self {
+                format: ReportFormat::JUnit,
+                path,
+            });
+        }
+
+        if let Some(path) = args.opt_value_from_str("--tap")? {
+            reports.push(Self {
+                format: ReportFormat::Tap,
+                path,
+            });
+        }
+
+        Ok(reports)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Outcome {
+    Passed,
+    /// `kind` is the status of a failed part, or `mismatch` for a wrong answer.
+    Failed {
+        kind: String,
+        message: String,
+    },
+    Skipped(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct TestCase {
+    day: Day,
+    part: Part,
+    answer: Option<String>,
+    duration: Duration,
+    outcome: Outcome,
+}
+
+/// Collects the results of a run to write them as reports.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Report {
+    cases: Vec<TestCase>,
+}
+
+impl Report {
+    /// Adds a part result as a test case. It fails if the part failed or its answer does not match
+    /// the expected one, and is skipped if the part is not solved yet.
+    pub(crate) fn add(&mut self, result: &PartResult, verification: &Verification) {
+        let outcome = match (&result.status, verification) {
+            (PartStatus::Failed(_) | PartStatus::Panicked(_) | PartStatus::TimedOut(_), _) => {
+                Outcome::Failed {
+                    kind: result.status.as_str().into(),
+                    message: result.status.describe_failure().unwrap_or_default(),
+                }
+            }
+            (_, Verification::Mismatch { expected, actual }) => Outcome::Failed {
+                kind: "mismatch".into(),
+                message: format!(
+                    "expected {expected}, got {}",
+                    actual.as_deref().unwrap_or("no answer")
+                ),
+            },
+            (PartStatus::Unsolved, _) => Outcome::Skipped("not solved".into()),
+            (PartStatus::Solved, _) => Outcome::Passed,
+        };
+
+        self.cases.push(TestCase {
+            day: result.day,
+            part: result.part,
+            answer: result.answer.as_ref().map(ToString::to_string),
+            duration: result.duration,
+            outcome,
+        });
+    }
+
+    /// Adds a failed test case for a part that could not run because its input is missing.
+    pub(crate) fn add_missing_input(&mut self, day: Day, part: Part, message: &str) {
+        self.cases.push(TestCase {
+            day,
+            part,
+            answer: None,
+            duration: Duration::ZERO,
+            outcome: Outcome::Failed {
+                kind: "missing_input".into(),
+                message: format!("could not open input file: {message}"),
+            },
+        });
+    }
+
+    /// Writes the report in the format and to the path of `config`, creating parent directories.
+    pub(crate) fn write(&self, config: &ReportConfig) -> io::Result<()> {
+        let contents = match config.format {
+            ReportFormat::JUnit => self.to_junit(),
+            ReportFormat::Tap => self.to_tap(),
+        };
+
+        if let Some(parent) = config.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&config.path, contents)
+    }
+
+    /// Writes all reports of `configs`, reporting errors on stderr.
+    /// Returns whether all of them were written.
+    pub(crate) fn write_all(&self, configs: &[ReportConfig]) -> bool {
+        let mut is_written = true;
+
+        for config in configs {
+            if let Err(e) = self.write(config) {
+                eprintln!("Failed to write report to {}: {e}", config.path.display());
+                is_written = false;
+            }
+        }
+
+        is_written
+    }
+
+    fn to_junit(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+        let mut days: Vec<Day> = self.cases.iter().map(|case| case.day).collect();
+        days.sort_unstable();
+        days.dedup();
+
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"advent_of_code\" {}>",
+            summary_attributes(&self.cases.iter().collect::<Vec<_>>())
+        );
+
+        for day in days {
+            let cases: Vec<&TestCase> = self.cases.iter().filter(|case| case.day == day).collect();
+
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"day{day}\" {}>",
+                summary_attributes(&cases)
+            );
+
+            for case in cases {
+                let mut children: Vec<String> = vec![];
+
+                match &case.outcome {
+                    Outcome::Passed => {}
+                    Outcome::Failed { kind, message } => {
+                        children.push(format!(
+                            "<failure type=\"{}\" message=\"{}\"/>",
+                            escape_xml(kind),
+                            escape_xml(message)
+                        ));
+                    }
+                    Outcome::Skipped(message) => {
+                        children.push(format!("<skipped message=\"{}\"/>", escape_xml(message)));
+                    }
+                }
+
+                if let Some(answer) = &case.answer {
+                    children.push(format!(
+                        "<system-out>answer: {}</system-out>",
+                        escape_xml(answer)
+                    ));
+                }
+
+                let _ = write!(
+                    xml,
+                    "    <testcase classname=\"day{day}\" name=\"part_{}\" time=\"{:.6}\"",
+                    case.part,
+                    case.duration.as_secs_f64()
+                );
+
+                if children.is_empty() {
+                    let _ = writeln!(xml, "/>");
+                } else {
+                    let _ = writeln!(xml, ">");
+                    for child in children {
+                        let _ = writeln!(xml, "      {child}");
+                    }
+                    let _ = writeln!(xml, "    </testcase>");
+                }
+            }
+
+            let _ = writeln!(xml, "  </testsuite>");
+        }
+
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    fn to_tap(&self) -> String {
+        let mut tap = format!("TAP version 13\n1..{}\n", self.cases.len());
+
+        for (index, case) in self.cases.iter().enumerate() {
+            let number = index + 1;
+            let description = format!("day {} part {}", case.day, case.part);
+
+            let _ = match &case.outcome {
+                Outcome::Passed => writeln!(tap, "ok {number} - {description}"),
+                Outcome::Failed { .. } => writeln!(tap, "not ok {number} - {description}"),
+                Outcome::Skipped(message) => {
+                    writeln!(tap, "ok {number} - {description} # SKIP {message}")
+                }
+            };
+
+            // NOTE: details are added as a YAML block, strings are quoted as JSON which is valid YAML.
+            let _ = writeln!(tap, "  ---");
+            if let Outcome::Failed { kind, message } = &case.outcome {
+                let _ = writeln!(tap, "  type: {}", quote_yaml(kind));
+                let _ = writeln!(tap, "  message: {}", quote_yaml(message));
+            }
+            if let Some(answer) = &case.answer {
+                let _ = writeln!(tap, "  answer: {}", quote_yaml(answer));
+            }
+            let _ = writeln!(
+                tap,
+                "  duration_ms: {:.6}",
+                case.duration.as_secs_f64() * 1000.0
+            );
+            let _ = writeln!(tap, "  ...");
+        }
+
+        tap
+    }
+}
+
+/// The `tests`, `failures`, `skipped` and `time` attributes of a JUnit test suite.
+fn summary_attributes(cases: &[&TestCase]) -> String {
+    let failures = cases
+        .iter()
+        .filter(|case| matches!(case.outcome, Outcome::Failed { .. }))
+        .count();
+    let skipped = cases
+        .iter()
+        .filter(|case| matches!(case.outcome, Outcome::Skipped(_)))
+        .count();
+    let time = cases
+        .iter()
+        .map(|case| case.duration)
+        .sum::<Duration>()
+        .as_secs_f64();
+
+    format!(
+        "tests=\"{}\" failures=\"{failures}\" skipped=\"{skipped}\" time=\"{time:.6}\"",
+        cases.len()
+    )
+}
+
+/// Escapes text for XML attributes and elements.
+/// Control characters, which are invalid in XML 1.0 even if escaped, are dropped.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\r' | '\t' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Quotes text as a double-quoted YAML scalar.
+fn quote_yaml(text: &str) -> String {
+    tinyjson::JsonValue::String(text.into())
+        .stringify()
+        .unwrap_or_default()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{escape_xml, Report};
+    use crate::day;
+    use crate::template::answers::Verification;
+    use crate::template::runner::{PartResult, PartStatus};
+    use crate::template::Part;
+
+    fn part_result(part: Part, answer: Option<&str>) -> PartResult {
+        PartResult::mock(part, answer, Duration::from_micros(1500))
+    }
+
+    fn report() -> Report {
+        let mut report = Report::default();
+        report.add(&part_result(Part::One, Some("42")), &Verification::Match);
+        report.add(
+            &part_result(Part::Two, Some("30")),
+            &Verification::Mismatch {
+                expected: "31".into(),
+                actual: Some("30".into()),
+            },
+        );
+        report.add(
+            &PartResult {
+                day: day!(2),
+                status: PartStatus::Panicked("oh <no>".into()),
+                ..part_result(Part::One, None)
+            },
+            &Verification::Unknown,
+        );
+        report.add(
+            &PartResult {
+                day: day!(2),
+                ..part_result(Part::Two, None)
+            },
+            &Verification::Unknown,
+        );
+        report
+    }
+
+    #[test]
+    fn writes_junit() {
+        let xml = report().to_junit();
+
+        assert!(xml.contains(
+            "<testsuites name=\"advent_of_code\" tests=\"4\" failures=\"2\" skipped=\"1\" time=\"0.006000\">"
+        ));
+        assert!(xml.contains(
+            "<testsuite name=\"day01\" tests=\"2\" failures=\"1\" skipped=\"0\" time=\"0.003000\">"
+        ));
+        assert!(xml.contains(
+            "<testcase classname=\"day01\" name=\"part_1\" time=\"0.001500\">\n      <system-out>answer: 42</system-out>\n    </testcase>"
+        ));
+        assert!(xml.contains("<failure type=\"mismatch\" message=\"expected 31, got 30\"/>"));
+        assert!(xml.contains("<failure type=\"panicked\" message=\"panicked: oh &lt;no&gt;\"/>"));
+        assert!(xml.contains("<skipped message=\"not solved\"/>"));
+        assert!(xml.contains("name=\"part_2\" time=\"0.001500\">\n      <skipped"));
+    }
+
+    #[test]
+    fn writes_tap() {
+        let tap = report().to_tap();
+        let lines: Vec<&str> = tap.lines().collect();
+
+        assert_eq!(
+            lines[0..3],
+            ["TAP version 13", "1..4", "ok 1 - day 01 part 1"]
+        );
+        assert!(tap.contains("not ok 2 - day 01 part 2\n  ---\n  type: \"mismatch\"\n  message: \"expected 31, got 30\"\n  answer: \"30\"\n"));
+        assert!(tap.contains("not ok 3 - day 02 part 1\n  ---\n  type: \"panicked\"\n  message: \"panicked: oh <no>\"\n  duration_ms"));
+        assert!(tap.contains("ok 4 - day 02 part 2 # SKIP not solved\n"));
+        assert!(tap.contains("  duration_ms: 1.500000\n  ...\n"));
+    }
+
+    #[test]
+    fn reports_missing_inputs() {
+        let mut report = Report::default();
+        report.add_missing_input(day!(3), Part::One, "No such file or directory");
+
+        assert!(report.to_junit().contains(
+            "<failure type=\"missing_input\" message=\"could not open input file: No such file or directory\"/>"
+        ));
+        assert!(report.to_tap().contains("not ok 1 - day 03 part 1\n"));
+    }
+
+    #[test]
+    fn escapes_xml() {
+        assert_eq!(
+            escape_xml("a & \"b\" <c>\u{1b}[31m"),
+            "a &amp; &quot;b&quot; &lt;c&gt;[31m"
+        );
+    }
+}
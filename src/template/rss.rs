@@ -0,0 +1,47 @@
+/// Reads the peak resident set size (RSS) of the current process from `/proc`.
+/// Only supported on Linux, other platforms report no values.
+use std::fs;
+
+/// Resets the peak RSS of the current process to its current RSS.
+/// Returns `false` if this is not supported, in which case the peak covers the whole process lifetime.
+pub fn reset_peak() -> bool {
+    // see: https://www.kernel.org/doc/html/latest/filesystems/proc.html, "5" resets the peak RSS.
+    fs::write("/proc/self/clear_refs", "5").is_ok()
+}
+
+/// The peak RSS of the current process in bytes since it started or since the last [`reset_peak`].
+pub fn peak_bytes() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    parse_vm_hwm(&status)
+}
+
+/// Parses the `VmHWM` ("high water mark") line of a `/proc/<pid>/status` file to bytes.
+fn parse_vm_hwm(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes: u64 = line
+        .trim_start_matches("VmHWM:")
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_vm_hwm;
+
+    #[test]
+    fn parses_peak_rss() {
+        let status = "Name:\tadvent_of_code\nVmPeak:\t   10240 kB\nVmHWM:\t    2048 kB\nVmRSS:\t    1024 kB\n";
+        assert_eq!(parse_vm_hwm(status), Some(2048 * 1024));
+    }
+
+    #[test]
+    fn handles_missing_peak_rss() {
+        assert_eq!(parse_vm_hwm("Name:\tadvent_of_code\n"), None);
+    }
+}
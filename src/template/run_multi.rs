@@ -1,257 +1,551 @@
-use std::{collections::HashSet, io};
-
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::any::Any;
+use std::collections::{BTreeMap, HashSet};
+use std::io::{self, stdout, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Once};
+use std::thread;
+use std::time::Duration;
+
+use crate::template::answers::Answers;
+use crate::template::bench::Statistic;
+use crate::template::report::Report;
+use crate::template::rss;
+use crate::template::runner::{
+    format_bytes, format_part_result, OutputFormat, PartResult, PartStatus, RunnerConfig, Verbosity,
+};
+use crate::template::{
+    find_solution, Day, Part, Registry, Solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Prefix of the names of threads that run solution parts.
+const PART_THREAD_PREFIX: &str = "solution-part-";
 
-    let mut need_space = false;
+const ANSI_CLEAR_LINE: &str = "\r\x1b[2K";
+
+/// What happened when trying to run a single day.
+pub enum DayOutcome {
+    NotSolved,
+    MissingInput(io::Error),
+    Ran(Vec<PartResult>),
+}
+
+/// Runs the solutions for a set of days in-process and prints their output in day order.
+/// With [`OutputFormat::Json`], only the JSON records of each part are printed.
+///
+/// Every part runs on its own thread: panics are captured and, if a `timeout` is configured,
+/// parts that take longer are abandoned. Both are reported as failed parts, like parts that
+/// return an error, and listed in a summary after all days have run. An abandoned part keeps
+/// running in the background, so a day with one is left out of the timings and a warning is
+/// printed that the measurements after it are unreliable.
+///
+/// If `jobs` is greater than one, days run concurrently on that many threads. Their output is
+/// buffered and printed once all previous days have finished.
+///
+/// If `reports` are configured, they are written once all days have run, checking answers against
+/// the ones that are expected for the input source.
+pub fn run_multi(
+    solutions: &Registry,
+    days_to_run: &HashSet<Day>,
+    config: &RunnerConfig,
+) -> Option<Timings> {
+    silence_part_panics();
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let mut results: Vec<PartResult> = vec![];
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let timings = if config.jobs > 1 {
+        run_parallel(solutions, &days, config, &mut results)
+    } else {
+        run_serial(solutions, &days, config, &mut results)
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    let failed_parts: Vec<&PartResult> = results
+        .iter()
+        .filter(|result| result.status.describe_failure().is_some())
+        .collect();
 
-    if is_timed {
+    if config.format == OutputFormat::Text && !failed_parts.is_empty() {
+        print_failures(&failed_parts);
+    }
+
+    if !config.reports.is_empty() {
+        let mut report = Report::default();
+        for result in &results {
+            let expected = Answers::read_for_input(result.day, &config.input);
+            report.add(result, &expected.verify(result));
+        }
+        report.write_all(&config.reports);
+    }
+
+    if config.bench.is_some() {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
+        if config.format == OutputFormat::Text {
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
         Some(timings)
     } else {
         None
     }
 }
 
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    IO(io::Error),
-}
+/// Runs days one after another, streaming the output of each part as soon as it finishes.
+fn run_serial(
+    solutions: &Registry,
+    days: &[Day],
+    config: &RunnerConfig,
+    all_results: &mut Vec<PartResult>,
+) -> Vec<Timing> {
+    let mut timings: Vec<Timing> = Vec::with_capacity(days.len());
+    let mut has_detached_parts = false;
+
+    for (index, day) in days.iter().enumerate() {
+        print!("{}", format_header(*day, index, config.format));
+
+        // NOTE: the peak RSS is process-wide, so it's only measured when days run one after another
+        // and no abandoned part is still allocating in the background.
+        let is_rss_reset = !has_detached_parts && rss::reset_peak();
+        // NOTE: parts run silently on their own threads, a part that times out must not print
+        // later on. The progress of benchmarks is shown from here instead.
+        let show_progress = config.format == OutputFormat::Text
+            && config.verbosity != Verbosity::Quiet
+            && config.bench.is_some();
+        let outcome = run_day(
+            solutions,
+            *day,
+            config,
+            |part| {
+                if show_progress {
+                    print!("Part {part}: {ANSI_ITALIC}benching{ANSI_RESET}");
+                    let _ = stdout().flush();
+                }
+            },
+            |result| {
+                if show_progress {
+                    print!("{ANSI_CLEAR_LINE}");
+                }
+                print_part(result, config);
+            },
+        );
+        let max_rss_bytes = if is_rss_reset {
+            rss::peak_bytes()
+        } else {
+            None
+        };
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+        print_outcome(&outcome, config, false);
+        if let DayOutcome::Ran(results) = outcome {
+            if has_timed_out(&results) {
+                warn_detached(*day, &mut has_detached_parts);
+            } else {
+                if let (OutputFormat::Text, Some(bytes)) = (config.format, max_rss_bytes) {
+                    println!("Peak memory: {}", format_bytes(bytes));
+                }
+                timings.push(collect_timing(
+                    *day,
+                    &results,
+                    max_rss_bytes,
+                    config.bench.map(|bench| bench.statistic),
+                ));
+            }
+            all_results.extend(results);
+        }
     }
-}
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    timings
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+/// Runs days on `jobs` threads, printing each day's buffered output in day order.
+fn run_parallel(
+    solutions: &Registry,
+    days: &[Day],
+    config: &RunnerConfig,
+    all_results: &mut Vec<PartResult>,
+) -> Vec<Timing> {
+    let mut timings: Vec<Timing> = Vec::with_capacity(days.len());
+    let mut has_detached_parts = false;
+
+    let next_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..config.jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next_index = &next_index;
+
+            scope.spawn(move || loop {
+                let index = next_index.fetch_add(1, Ordering::SeqCst);
+                let Some(day) = days.get(index) else {
+                    break;
+                };
+                let outcome = run_day(solutions, *day, config, |_| {}, |_| {});
+                if sender.send((index, outcome)).is_err() {
+                    break;
+                }
+            });
+        }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        drop(sender);
+
+        let mut pending: BTreeMap<usize, DayOutcome> = BTreeMap::new();
+        let mut next_to_print = 0;
+
+        for (index, outcome) in receiver {
+            pending.insert(index, outcome);
+
+            while let Some(outcome) = pending.remove(&next_to_print) {
+                let day = days[next_to_print];
+                print!("{}", format_header(day, next_to_print, config.format));
+                print_outcome(&outcome, config, true);
+                if let DayOutcome::Ran(results) = outcome {
+                    if has_timed_out(&results) {
+                        warn_detached(day, &mut has_detached_parts);
+                    } else {
+                        timings.push(collect_timing(
+                            day,
+                            &results,
+                            None,
+                            config.bench.map(|bench| bench.statistic),
+                        ));
+                    }
+                    all_results.extend(results);
+                }
+                next_to_print += 1;
+            }
         }
+    });
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    timings
+}
 
-        if is_release {
-            args.push("--release");
-        }
+/// Runs every part of a single day without printing its results, e.g. to check its answers.
+/// Panics and timeouts are isolated like in [`run_multi`].
+/// The input and timeout are taken from the `config`.
+pub fn run_day_silent(solutions: &Registry, day: Day, config: &RunnerConfig) -> DayOutcome {
+    silence_part_panics();
+    let config = RunnerConfig {
+        bench: None,
+        ..config.clone()
+    };
+    run_day(solutions, day, &config, |_| {}, |_| {})
+}
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+/// Runs a single part of a day against `input` without printing its result.
+/// Panics and timeouts are isolated like in [`run_multi`].
+/// Returns `None` if the day is not solved or its solution does not implement the part.
+pub fn run_part_silent(
+    solutions: &Registry,
+    day: Day,
+    part: Part,
+    input: &str,
+    config: &RunnerConfig,
+) -> Option<PartResult> {
+    silence_part_panics();
+
+    let solution = find_solution(solutions, day)?;
+    if !solution.parts().contains(&part) {
+        return None;
+    }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+    run_part_isolated(solution, part, &Arc::from(input), config)
+}
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+/// Runs every part of a day, calling `on_start` before a part starts and `on_part` whenever
+/// a part finishes.
+fn run_day(
+    solutions: &Registry,
+    day: Day,
+    config: &RunnerConfig,
+    mut on_start: impl FnMut(Part),
+    mut on_part: impl FnMut(&PartResult),
+) -> DayOutcome {
+    let Some(solution) = find_solution(solutions, day) else {
+        return DayOutcome::NotSolved;
+    };
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+    let input: Arc<str> = match config.input.read(day) {
+        Ok(input) => input.into(),
+        Err(e) => return DayOutcome::MissingInput(e),
+    };
 
-        let mut output = vec![];
+    let results = solution
+        .parts()
+        .iter()
+        .filter_map(|part| {
+            on_start(*part);
+            let result = run_part_isolated(solution, *part, &input, config)?;
+            on_part(&result);
+            Some(result)
+        })
+        .collect();
+
+    DayOutcome::Ran(results)
+}
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
+/// Runs a part on a separate thread, capturing panics and abandoning it after the configured timeout.
+fn run_part_isolated(
+    solution: &'static dyn Solution,
+    part: Part,
+    input: &Arc<str>,
+    config: &RunnerConfig,
+) -> Option<PartResult> {
+    let day = solution.day();
+    let input = Arc::clone(input);
+    // NOTE: results are printed by the caller, and answers are never submitted for multiple days.
+    let part_config = RunnerConfig {
+        format: OutputFormat::Silent,
+        submit: None,
+        ..config.clone()
+    };
+    let (sender, receiver) = mpsc::channel();
+
+    let spawned = thread::Builder::new()
+        .name(format!("{PART_THREAD_PREFIX}{day}-{part}"))
+        .spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                solution.run_part(part, &input, &part_config)
+            }));
+            let _ = sender.send(result);
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+    let failed = |status: PartStatus, duration: Duration| PartResult {
+        day,
+        part,
+        answer: None,
+        status,
+        duration,
+        samples: 1,
+        stats: None,
+        allocs: None,
+    };
+
+    if let Err(e) = spawned {
+        return Some(failed(
+            PartStatus::Panicked(format!("could not spawn thread: {e}")),
+            Duration::ZERO,
+        ));
+    }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    let received = match config.timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).map_err(|_| timeout),
+        None => receiver.recv().map_err(|_| Duration::ZERO),
+    };
 
-        Ok(output)
+    match received {
+        Ok(Ok(result)) => result,
+        Ok(Err(payload)) => Some(failed(
+            PartStatus::Panicked(panic_message(payload.as_ref())),
+            Duration::ZERO,
+        )),
+        // NOTE: a timed out thread can not be stopped, it keeps running detached.
+        Err(timeout) => Some(failed(PartStatus::TimedOut(timeout), timeout)),
     }
+}
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+/// Whether a part of a day was abandoned after its timeout.
+fn has_timed_out(results: &[PartResult]) -> bool {
+    results
+        .iter()
+        .any(|result| matches!(result.status, PartStatus::TimedOut(_)))
+}
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+/// Warns once that abandoned parts skew what is measured after them.
+fn warn_detached(day: Day, has_warned: &mut bool) {
+    if !*has_warned {
+        eprintln!(
+            "Day {day} timed out and keeps running in the background, \
+            the timings and memory usage measured after it are unreliable."
+        );
+        *has_warned = true;
+    }
+}
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+/// Extracts the message of a panic payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+/// Keeps panics of solution parts from printing to stderr, they are reported as part results instead.
+fn silence_part_panics() {
+    static INSTALL_HOOK: Once = Once::new();
 
-                timings.total_nanos += nanos;
-            });
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let is_part_thread = thread::current()
+                .name()
+                .is_some_and(|name| name.starts_with(PART_THREAD_PREFIX));
+
+            if !is_part_thread {
+                default_hook(info);
+            }
+        }));
+    });
+}
 
-        timings
+fn format_header(day: Day, index: usize, format: OutputFormat) -> String {
+    if format != OutputFormat::Text {
+        return String::new();
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    let space = if index > 0 { "\n" } else { "" };
+    format!("{space}{ANSI_BOLD}Day {day}{ANSI_RESET}\n------\n")
+}
+
+fn print_part(result: &PartResult, config: &RunnerConfig) {
+    match config.format {
+        OutputFormat::Text => print!("{}", format_part_result(result, config.verbosity)),
+        OutputFormat::Json => println!("{}", result.to_json_line()),
+        OutputFormat::Silent => {}
     }
+}
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+/// Prints the output of a day. Set `include_parts` if part results have not been printed yet.
+fn print_outcome(outcome: &DayOutcome, config: &RunnerConfig, include_parts: bool) {
+    let format = config.format;
+    match outcome {
+        DayOutcome::NotSolved => {
+            if format == OutputFormat::Text {
+                println!("Not solved.");
+            }
+        }
+        DayOutcome::MissingInput(e) => {
+            eprintln!("Could not open input file: {e}");
+        }
+        DayOutcome::Ran(results) if include_parts => {
+            for result in results {
+                print_part(result, config);
+            }
+        }
+        DayOutcome::Ran(_) => {}
     }
+}
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+/// Prints the parts that returned an error, panicked or timed out.
+fn print_failures(failed_parts: &[&PartResult]) {
+    println!(
+        "\n{ANSI_BOLD}{} part(s) failed:{ANSI_RESET}",
+        failed_parts.len()
+    );
+    for result in failed_parts {
+        println!(
+            "Day {} / Part {}: {}",
+            result.day,
+            result.part,
+            result.status.describe_failure().unwrap_or_default()
+        );
     }
+}
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+/// Collects the durations of all solved parts of a day from their records.
+pub fn collect_timing(
+    day: Day,
+    results: &[PartResult],
+    max_rss_bytes: Option<u64>,
+    statistic: Option<Statistic>,
+) -> Timing {
+    let mut timing = Timing {
+        day,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+        max_rss_bytes,
+        statistic,
+    };
 
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+    for result in results
+        .iter()
+        .filter(|result| result.status == PartStatus::Solved)
+    {
+        let duration_str = format!("{:.1?}", result.duration);
+
+        match result.part {
+            Part::One => timing.part_1 = Some(duration_str),
+            Part::Two => timing.part_2 = Some(duration_str),
         }
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+        #[allow(clippy::cast_precision_loss)]
+        {
+            timing.total_nanos += result.duration.as_nanos() as f64;
         }
     }
+
+    timing
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{collect_timing, has_timed_out};
+    use crate::{
+        day,
+        template::bench::Statistic,
+        template::runner::{PartResult, PartStatus},
+        template::Part,
+    };
+
+    fn part_result(part: Part, answer: Option<&str>, nanos: u64) -> PartResult {
+        PartResult::mock(part, answer, Duration::from_nanos(nanos))
+    }
+
+    #[test]
+    fn collects_timings() {
+        let res = collect_timing(
+            day!(1),
+            &[
+                part_result(Part::One, Some("0"), 74),
+                part_result(Part::Two, Some("10"), 74_130_000),
+            ],
+            Some(2048),
+            Some(Statistic::Median),
+        );
+        assert_eq!(res.total_nanos, 74_130_074_f64);
+        assert_eq!(res.part_1.unwrap(), "74.0ns");
+        assert_eq!(res.part_2.unwrap(), "74.1ms");
+        assert_eq!(res.max_rss_bytes, Some(2048));
+        assert_eq!(res.statistic, Some(Statistic::Median));
+    }
+
+    #[test]
+    fn collects_missing_parts() {
+        let res = collect_timing(
+            day!(1),
+            &[
+                part_result(Part::One, None, 100),
+                part_result(Part::Two, None, 100),
+            ],
+            None,
+            None,
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
+    }
+
+    #[test]
+    fn detects_timed_out_days() {
+        let solved = part_result(Part::One, Some("1"), 100);
+        let mut timed_out = part_result(Part::Two, None, 100);
+        timed_out.status = PartStatus::TimedOut(Duration::from_secs(1));
+
+        assert_eq!(has_timed_out(&[solved.clone()]), false);
+        assert_eq!(has_timed_out(&[solved, timed_out]), true);
+    }
 }
@@ -1,165 +1,715 @@
 /// Encapsulates code that interacts with solution functions.
-use std::fmt::Display;
+use std::collections::HashMap;
+use std::error::Error;
 use std::hint::black_box;
-use std::io::{stdout, Write};
+use std::io::{self, stdout, Write};
+use std::process;
 use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
 
+use tinyjson::JsonValue;
+
+use crate::template::alloc::{self, AllocStats};
+use crate::template::answers::Answers;
+use crate::template::bench::{BenchConfig, BenchStats};
+use crate::template::debug;
+use crate::template::input::InputSource;
+use crate::template::report::ReportConfig;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    aoc_cli, Answer, Day, Part, PartOutput, Solution, SubmitError, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// How the results of solution parts are reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable lines, e.g. `Part 1: 42 (1.2ms)`.
+    Text,
+    /// One JSON record per part and line, see [`PartResult`].
+    Json,
+    /// Nothing is printed, results are only returned.
+    Silent,
+}
+
+/// How much the runner prints besides the results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Only final results, without progress output.
+    Quiet,
+    #[default]
+    Normal,
+    /// Additional benchmark statistics.
+    Verbose,
+}
+
+/// Settings of a solution run, built from the command-line with [`RunnerConfig::from_env`]
+/// or constructed directly, e.g. to drive solutions from tests or other tools:
+///
+/// ```ignore
+/// let config = RunnerConfig {
+///     format: OutputFormat::Silent,
+///     ..RunnerConfig::default()
+/// };
+/// let results = run_solution(SOLUTION, &config)?;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunnerConfig {
+    /// Benchmark parts if set, otherwise they are run once.
+    pub bench: Option<BenchConfig>,
+    /// The part whose answer is submitted via aoc-cli.
+    pub submit: Option<Part>,
+    pub verbosity: Verbosity,
+    pub format: OutputFormat,
+    pub input: InputSource,
+    /// Wall-clock limit per part. Only used by the multi-day runner.
+    pub timeout: Option<Duration>,
+    /// Number of days that run concurrently. Only used by the multi-day runner.
+    pub jobs: usize,
+    /// Test reports that are written after a run. Only used by the multi-day runner and `verify`.
+    pub reports: Vec<ReportConfig>,
+}
+
+impl Default for RunnerConfig {
+    fn default() -> Self {
+        Self {
+            bench: None,
+            submit: None,
+            verbosity: Verbosity::default(),
+            format: OutputFormat::Text,
+            input: InputSource::default(),
+            timeout: None,
+            jobs: 1,
+            reports: vec![],
+        }
+    }
+}
+
+impl RunnerConfig {
+    /// Reads runner settings from command-line arguments, falling back to defaults:
+    /// `--time` with the settings of [`BenchConfig::from_args`], `--submit <part>`, `--quiet` or `--verbose`,
+    /// `--json`, the input source of [`InputSource::from_args`], `--timeout <secs>`, `--jobs <n>`
+    /// and the reports of [`ReportConfig::from_args`].
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn Error>> {
+        let defaults = Self::default();
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+        let bench = if args.contains("--time") {
+            Some(BenchConfig::from_args(args)?)
+        } else {
+            None
+        };
+
+        let verbosity = match (args.contains("--quiet"), args.contains("--verbose")) {
+            (false, false) => defaults.verbosity,
+            (true, false) => Verbosity::Quiet,
+            (false, true) => Verbosity::Verbose,
+            (true, true) => {
+                return Err("only one of `--quiet` and `--verbose` can be passed".into())
+            }
+        };
+
+        let format = if args.contains("--json") {
+            OutputFormat::Json
+        } else {
+            defaults.format
+        };
+
+        let timeout = args.opt_value_from_fn("--timeout", |s| {
+            let secs: f64 = s.parse().map_err(|e| format!("{e}"))?;
+            Duration::try_from_secs_f64(secs).map_err(|e| format!("{e}"))
+        })?;
+
+        Ok(Self {
+            bench,
+            submit: args.opt_value_from_str("--submit")?,
+            verbosity,
+            format,
+            input: InputSource::from_args(args)?,
+            timeout,
+            jobs: args.opt_value_from_str("--jobs")?.unwrap_or(defaults.jobs),
+            reports: ReportConfig::from_args(args)?,
+        })
+    }
+
+    /// Reads runner settings from the arguments of the current process.
+    pub fn from_env() -> Self {
+        match Self::from_args(&mut pico_args::Arguments::from_env()) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Invalid arguments: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Whether a solution part produced an answer, or how it failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    Unsolved,
+    /// The part returned the contained error.
+    Failed(String),
+    /// The part panicked with the contained message.
+    Panicked(String),
+    /// The part did not finish within the contained duration.
+    TimedOut(Duration),
+}
+
+/// The outcome of running a single part of a solution.
+/// Serializes to a single-line JSON record.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub day: Day,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub status: PartStatus,
+    /// Duration of a single run, or the selected statistic if benchmarked.
+    pub duration: Duration,
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+    /// Allocations of a single run, if the counting allocator is enabled.
+    pub allocs: Option<AllocStats>,
+}
+
+/// Reads the input of a solution from `config.input` and runs all of its parts.
+pub fn run_solution(solution: &dyn Solution, config: &RunnerConfig) -> io::Result<Vec<PartResult>> {
+    let input = config.input.read(solution.day())?;
+    Ok(solution.run(&input, config))
+}
+
+/// Runs a single part and reports its result according to the `config`.
+/// Parts can return an `Option` or a `Result`, see [`PartOutput`].
+pub fn run_part<I: Copy, T: PartOutput>(
+    func: impl Fn(I) -> T,
+    input: I,
+    day: Day,
+    part: Part,
+    config: &RunnerConfig,
+) -> PartResult {
     let part_str = format!("Part {part}");
+    let show_progress = config.format == OutputFormat::Text && config.verbosity != Verbosity::Quiet;
+    let show_debug = config.format == OutputFormat::Text && config.verbosity == Verbosity::Verbose;
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    // NOTE: debug output is only printed for the first run, never while benching.
+    debug::set_enabled(show_debug);
+    let (result, duration, samples, stats, allocs) =
+        run_timed(func, input, config.bench, |result| {
+            debug::set_enabled(false);
+            if show_progress {
+                print_result(&result.to_answer(), &part_str);
+                if config.bench.is_some() {
+                    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                    let _ = stdout().flush();
+                }
+            }
+        });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (answer, status) = match result.to_answer() {
+        Ok(Some(answer)) => (Some(answer), PartStatus::Solved),
+        Ok(None) => (None, PartStatus::Unsolved),
+        Err(e) => (None, PartStatus::Failed(e)),
+    };
+
+    let part_result = PartResult {
+        day,
+        part,
+        status,
+        answer,
+        duration,
+        samples,
+        stats,
+        allocs,
+    };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    match config.format {
+        OutputFormat::Text => {
+            if show_progress {
+                print!("\r");
+            }
+            print!("{}", format_part_result(&part_result, config.verbosity));
+        }
+        OutputFormat::Json => println!("{}", part_result.to_json_line()),
+        OutputFormat::Silent => {}
+    }
+
+    if config.submit != Some(part) {
+        return part_result;
     }
+
+    if let Ok(output) = submit_answer(&part_result) {
+        if aoc_cli::is_accepted(&output) {
+            record_answer(part_result.answer.as_ref(), day, part);
+        }
+    }
+
+    part_result
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether a `bench` config is passed:
+///  1. if not, the function is executed once.
+///  2. if passed, the function is warmed up and benched until the target duration or the sample cap is reached.
+///
+/// Allocations are only counted for the first run.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    bench_config: Option<BenchConfig>,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, u128, Option<BenchStats>, Option<AllocStats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, allocs) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        alloc::measure(|| func(input))
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let Some(config) = bench_config else {
+        return (result, base_time, 1, None, allocs);
     };
 
-    (result, run.0, run.1)
-}
-
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+    let timers = bench(func, input, &config);
+    let samples = timers.len() as u128;
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    match BenchStats::from_samples(&timers) {
+        Some(stats) => (
+            result,
+            stats.get(config.statistic),
+            samples,
+            Some(stats),
+            allocs,
+        ),
+        None => (result, base_time, 1, None, allocs),
+    }
+}
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, config: &BenchConfig) -> Vec<Duration> {
+    let warmup = Instant::now();
+    while warmup.elapsed() < config.warmup {
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
+    let bench = Instant::now();
 
-    for _ in 0..bench_iterations {
+    while !config.is_done(timers.len() as u128, bench.elapsed()) {
         let timer = Instant::now();
         black_box(func(black_box(input)));
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    timers
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(
+    duration: &Duration,
+    samples: u128,
+    stats: Option<&BenchStats>,
+    verbosity: Verbosity,
+) -> String {
+    match stats {
+        None if samples == 1 => format!(" ({duration:.1?})"),
+        None => format!(" ({duration:.1?} @ {samples} samples)"),
+        Some(stats) if verbosity == Verbosity::Verbose => format!(
+            " ({duration:.1?} @ {samples} samples; median {:.1?}, min {:.1?}, p95 {:.1?}, max {:.1?}, σ {:.1?}, {} outliers)",
+            stats.median, stats.min, stats.p95, stats.max, stats.stddev, stats.outliers
+        ),
+        Some(stats) => format!(
+            " ({duration:.1?} @ {samples} samples; median {:.1?}, min {:.1?}, p95 {:.1?}, σ {:.1?})",
+            stats.median, stats.min, stats.p95, stats.stddev
+        ),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_allocs(allocs: Option<&AllocStats>) -> String {
+    match allocs {
+        None => String::new(),
+        Some(allocs) => format!(
+            " [{} allocs, {} total, {} peak]",
+            allocs.count,
+            format_bytes(allocs.bytes),
+            format_bytes(allocs.peak_bytes)
+        ),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
-    let is_intermediate_result = duration_str.is_empty();
+/// Formats a number of bytes in binary units, e.g. `1.5KiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{bytes}B"),
+        1024..1_048_576 => format!("{:.1}KiB", bytes as f64 / 1024_f64),
+        _ => format!("{:.1}MiB", bytes as f64 / 1_048_576_f64),
+    }
+}
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
+/// Prints the intermediate result of a part, before it's benched.
+fn print_result(answer: &Result<Option<Answer>, String>, part: &str) {
+    match answer {
+        Ok(Some(answer)) => {
+            if answer.is_multiline() {
+                print!("{part}: ▼ ");
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
+                print!("{part}: {ANSI_BOLD}{answer}{ANSI_RESET}");
             }
         }
-        None => {
-            if is_intermediate_result {
-                print!("{part}: ✖");
-            } else {
-                print!("\r");
-                println!("{part}: ✖             ");
-            }
+        Ok(None) => {
+            print!("{part}: ✖");
+        }
+        Err(e) => {
+            // NOTE: only the first line, this is overwritten by the final output.
+            print!("{part}: ✖ error: {}", e.lines().next().unwrap_or_default());
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Formats the final, human-readable output of a part, including its trailing newline.
+pub fn format_part_result(result: &PartResult, verbosity: Verbosity) -> String {
+    let part = format!("Part {}", result.part);
+    let duration_str = format!(
+        "{}{}",
+        format_duration(
+            &result.duration,
+            result.samples,
+            result.stats.as_ref(),
+            verbosity
+        ),
+        format_allocs(result.allocs.as_ref())
+    );
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+    match &result.answer {
+        Some(answer) if answer.is_multiline() => {
+            format!("{part}: ▼ {duration_str}\n{answer}\n")
+        }
+        Some(answer) => format!("{part}: {ANSI_BOLD}{answer}{ANSI_RESET}{duration_str}\n"),
+        None => match result.status.describe_failure() {
+            Some(failure) => format!("{part}: ✖ {failure}\n"),
+            None => format!("{part}: ✖             \n"),
+        },
     }
+}
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+/// Records an answer that was accepted on submit, so `cargo verify` can check it later on.
+fn record_answer(answer: Option<&Answer>, day: Day, part: Part) {
+    let Some(answer) = answer else {
+        return;
+    };
+
+    match Answers::record(day, part, &answer.to_string()) {
+        Ok(()) => eprintln!("Recorded accepted answer."),
+        Err(e) => eprintln!("Failed to record accepted answer: {e}"),
     }
+}
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+/// Submits the answer of a part via aoc-cli, which needs to be installed.
+/// Refuses to submit if the part failed or has no answer, or if the answer spans multiple lines.
+fn submit_answer(result: &PartResult) -> Result<Output, aoc_cli::AocCommandError> {
+    let (day, part) = (result.day, result.part);
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let submission = match &result.status {
+        PartStatus::Failed(message) => Err(SubmitError::Failed(message.clone())),
+        _ => result
+            .answer
+            .as_ref()
+            .ok_or(SubmitError::NoAnswer)
+            .and_then(Answer::to_submission),
     };
 
-    if part_submit != part {
-        return None;
-    }
+    let submission = match submission {
+        Ok(submission) => submission,
+        Err(e) => {
+            eprintln!("Refusing to submit part {part}: {e}.");
+            process::exit(1);
+        }
+    };
 
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    // NOTE: status output goes to stderr, stdout is reserved for results, e.g. JSON records.
+    eprintln!("Submitting result via aoc-cli...");
+    aoc_cli::submit(day, part, &submission)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl PartStatus {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Failed(_) => "failed",
+            PartStatus::Panicked(_) => "panicked",
+            PartStatus::TimedOut(_) => "timed_out",
+        }
+    }
+
+    /// Describes why a part failed, e.g. `error: <message>` or `timed out after 10s`.
+    pub fn describe_failure(&self) -> Option<String> {
+        match self {
+            PartStatus::Failed(message) => Some(format!("error: {message}")),
+            PartStatus::Panicked(message) => Some(format!("panicked: {message}")),
+            PartStatus::TimedOut(timeout) => Some(format!("timed out after {timeout:?}")),
+            PartStatus::Solved | PartStatus::Unsolved => None,
+        }
+    }
+}
+
+impl PartResult {
+    /// Serializes the result to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        // NOTE: `stringify` never emits line breaks, multiline answers are escaped.
+        JsonValue::from(self).stringify().unwrap_or_default()
+    }
+
+    /// A result of day 1 for tests, solved if it has an `answer` and unsolved otherwise.
+    #[cfg(feature = "test_lib")]
+    pub(crate) fn mock(part: Part, answer: Option<&str>, duration: Duration) -> Self {
+        Self {
+            day: crate::day!(1),
+            part,
+            answer: answer.map(Answer::new),
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            duration,
+            samples: 1,
+            stats: None,
+            allocs: None,
+        }
+    }
+}
+
+impl From<&PartResult> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "part".into(),
+            JsonValue::Number(f64::from(value.part.into_inner())),
+        );
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.to_string()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert(
+            "error".into(),
+            match &value.status {
+                PartStatus::Failed(message) | PartStatus::Panicked(message) => {
+                    JsonValue::String(message.clone())
+                }
+                _ => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "allocs".into(),
+            match &value.allocs {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&AllocStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &AllocStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("count".into(), JsonValue::Number(value.count as f64));
+        map.insert("bytes".into(), JsonValue::Number(value.bytes as f64));
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&BenchStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+        map.insert("mean_nanos".into(), nanos(value.mean));
+        map.insert("median_nanos".into(), nanos(value.median));
+        map.insert("min_nanos".into(), nanos(value.min));
+        map.insert("max_nanos".into(), nanos(value.max));
+        map.insert("p95_nanos".into(), nanos(value.p95));
+        map.insert("stddev_nanos".into(), nanos(value.stddev));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{OutputFormat, PartResult, PartStatus, RunnerConfig, Verbosity};
+    use crate::{
+        day,
+        template::{alloc::AllocStats, bench::BenchStats, input::InputSource, Answer, Part},
+    };
+
+    fn parse_config(args: &[&str]) -> Result<RunnerConfig, Box<dyn std::error::Error>> {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+        RunnerConfig::from_args(&mut args)
+    }
+
+    #[test]
+    fn defaults_runner_config() {
+        assert_eq!(parse_config(&[]).unwrap(), RunnerConfig::default());
+    }
+
+    #[test]
+    fn parses_runner_config() {
+        let config = parse_config(&[
+            "--quiet",
+            "--json",
+            "--submit",
+            "2",
+            "--input-set",
+            "examples",
+            "--timeout",
+            "1.5",
+            "--jobs",
+            "4",
+        ])
+        .unwrap();
+
+        assert_eq!(config.bench, None);
+        assert_eq!(config.submit, Some(Part::Two));
+        assert_eq!(config.verbosity, Verbosity::Quiet);
+        assert_eq!(config.format, OutputFormat::Json);
+        assert_eq!(config.input, InputSource::Set("examples".into()));
+        assert_eq!(config.timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(config.jobs, 4);
+
+        assert!(parse_config(&["--time"]).unwrap().bench.is_some());
+    }
+
+    #[test]
+    fn rejects_conflicting_verbosity() {
+        assert!(parse_config(&["--quiet", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn serializes_json_records() {
+        let result = PartResult {
+            day: day!(3),
+            part: Part::Two,
+            answer: Some(Answer::new("a (b @ 5 samples)\nc")),
+            status: PartStatus::Solved,
+            duration: Duration::from_nanos(74_130),
+            samples: 100,
+            stats: BenchStats::from_samples(&[Duration::from_nanos(74_130)]),
+            allocs: Some(AllocStats {
+                count: 3,
+                bytes: 2048,
+                peak_bytes: 1024,
+            }),
+        };
+
+        let line = result.to_json_line();
+        assert_eq!(line.contains('\n'), false);
+
+        let json = record(&line);
+        assert_eq!(json["day"], JsonValue::String("03".into()));
+        assert_eq!(json["part"], JsonValue::Number(2.0));
+        assert_eq!(
+            json["answer"],
+            JsonValue::String("a (b @ 5 samples)\nc".into())
+        );
+        assert_eq!(json["status"], JsonValue::String("solved".into()));
+        assert_eq!(json["error"], JsonValue::Null);
+        assert_eq!(json["nanos"], JsonValue::Number(74_130.0));
+        assert_eq!(json["samples"], JsonValue::Number(100.0));
+
+        let stats: &HashMap<String, JsonValue> = json["stats"].get().unwrap();
+        assert_eq!(stats["median_nanos"], JsonValue::Number(74_130.0));
+        let allocs: &HashMap<String, JsonValue> = json["allocs"].get().unwrap();
+        assert_eq!(allocs["peak_bytes"], JsonValue::Number(1024.0));
+    }
+
+    #[test]
+    fn serializes_unsolved_records() {
+        let json = record(&part_result(PartStatus::Unsolved).to_json_line());
+        assert_eq!(json["answer"], JsonValue::Null);
+        assert_eq!(json["status"], JsonValue::String("unsolved".into()));
+        assert_eq!(json["stats"], JsonValue::Null);
+        assert_eq!(json["allocs"], JsonValue::Null);
+    }
+
+    #[test]
+    fn serializes_failed_records() {
+        let json =
+            record(&part_result(PartStatus::Panicked("index out of bounds".into())).to_json_line());
+        assert_eq!(json["status"], JsonValue::String("panicked".into()));
+        assert_eq!(
+            json["error"],
+            JsonValue::String("index out of bounds".into())
+        );
+
+        let json = record(&part_result(PartStatus::Failed("invalid digit".into())).to_json_line());
+        assert_eq!(json["status"], JsonValue::String("failed".into()));
+        assert_eq!(json["error"], JsonValue::String("invalid digit".into()));
+
+        let json =
+            record(&part_result(PartStatus::TimedOut(Duration::from_secs(10))).to_json_line());
+        assert_eq!(json["status"], JsonValue::String("timed_out".into()));
+        assert_eq!(json["error"], JsonValue::Null);
+    }
+
+    fn part_result(status: PartStatus) -> PartResult {
+        PartResult {
+            status,
+            ..PartResult::mock(Part::One, None, Duration::ZERO)
+        }
+    }
+
+    fn record(line: &str) -> HashMap<String, JsonValue> {
+        let json: JsonValue = line.parse().unwrap();
+        json.get::<HashMap<String, JsonValue>>().unwrap().clone()
+    }
 }
@@ -0,0 +1,35 @@
+use crate::template::runner::{PartResult, RunnerConfig};
+use crate::template::{Day, Part};
+
+/// Common interface of a day's solution, implemented by the [`solution!`](crate::solution) macro.
+///
+/// This allows running solutions in-process, without invoking their binaries.
+pub trait Solution: Sync {
+    /// The day this solution belongs to.
+    fn day(&self) -> Day;
+
+    /// The parts this solution implements.
+    fn parts(&self) -> &'static [Part];
+
+    /// Runs a single part of the solution against `input`, see [`RunnerConfig`] for the settings.
+    /// Returns `None` if the solution does not implement the part.
+    fn run_part(&self, part: Part, input: &str, config: &RunnerConfig) -> Option<PartResult>;
+
+    /// Runs every part of the solution, see [`Solution::run_part`].
+    fn run(&self, input: &str, config: &RunnerConfig) -> Vec<PartResult> {
+        self.parts()
+            .iter()
+            .filter_map(|part| self.run_part(*part, input, config))
+            .collect()
+    }
+}
+/// A set of solutions, sorted by day.
+pub type Registry = [&'static dyn Solution];
+
+/// Looks up the solution for a given day.
+pub fn find_solution(solutions: &Registry, day: Day) -> Option<&'static dyn Solution> {
+    solutions
+        .iter()
+        .find(|solution| solution.day() == day)
+        .copied()
+}
@@ -1,9 +1,15 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::Error,
+    str::FromStr,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::bench::Statistic;
+use crate::template::{year, Day, Part};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static TIMINGS_FILE_NAME: &str = "timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -12,6 +18,10 @@ pub struct Timing {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// Peak resident set size of the process while running the day, if measured.
+    pub max_rss_bytes: Option<u64>,
+    /// The statistic the durations were measured with, `None` for timings stored without it.
+    pub statistic: Option<Statistic>,
 }
 
 /// Represents benchmark times for a set of days.
@@ -25,13 +35,13 @@ impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(year::data_dir().join(TIMINGS_FILE_NAME))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+        fs::read_to_string(year::data_dir().join(TIMINGS_FILE_NAME))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +61,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,11 +70,110 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    pub fn is_day_complete(&self, day: &Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == *day && t.part_1.is_some() && t.part_2.is_some())
     }
+
+    /// Compare every part of `new` with `self`. Parts without a stored timing are skipped,
+    /// as are days whose timings were measured with different statistics.
+    pub fn compare(&self, new: &Self) -> Vec<PartComparison> {
+        let mut comparisons = vec![];
+
+        for timing in &new.data {
+            let Some(stored) = self.data.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+            if !stored.has_statistic(timing.statistic) {
+                continue;
+            }
+
+            for part in [Part::One, Part::Two] {
+                if let (Some(old_nanos), Some(new_nanos)) =
+                    (stored.part_nanos(part), timing.part_nanos(part))
+                {
+                    comparisons.push(PartComparison {
+                        day: timing.day,
+                        part,
+                        old_nanos,
+                        new_nanos,
+                    });
+                }
+            }
+        }
+
+        comparisons
+    }
+
+    /// The days in `days` whose timings were measured with another statistic than `statistic`,
+    /// with the statistic they were measured with.
+    pub fn other_statistics(
+        &self,
+        days: &HashSet<Day>,
+        statistic: Statistic,
+    ) -> Vec<(Day, Statistic)> {
+        self.data
+            .iter()
+            .filter(|t| days.contains(&t.day))
+            .filter_map(|t| t.statistic.filter(|x| *x != statistic).map(|x| (t.day, x)))
+            .collect()
+    }
+}
+
+impl Timing {
+    /// Whether the timing can be compared with one measured with `statistic`.
+    /// Timings that don't know their statistic match any.
+    fn has_statistic(&self, statistic: Option<Statistic>) -> bool {
+        match (self.statistic, statistic) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        }
+    }
+
+    /// Duration of a part in nanoseconds, if it has been benched.
+    pub fn part_nanos(&self, part: Part) -> Option<f64> {
+        let duration = match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }?;
+        parse_duration_nanos(duration)
+    }
+}
+
+/// The change in duration of a single part between two sets of timings.
+#[derive(Clone, Debug)]
+pub struct PartComparison {
+    pub day: Day,
+    pub part: Part,
+    pub old_nanos: f64,
+    pub new_nanos: f64,
+}
+
+impl PartComparison {
+    /// Relative change of the duration, e.g. `0.1` if the part got 10% slower.
+    pub fn change(&self) -> f64 {
+        if self.old_nanos == 0_f64 {
+            return 0_f64;
+        }
+        (self.new_nanos - self.old_nanos) / self.old_nanos
+    }
+
+    /// Whether the part got slower by more than `threshold` (relative, e.g. `0.1` for 10%).
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Parse a duration string as stored in timings (e.g. `74.1µs`) to nanoseconds.
+/// for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+fn parse_duration_nanos(s: &str) -> Option<f64> {
+    [("ns", 1_f64), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)]
+        .iter()
+        .find_map(|(unit, factor)| {
+            let value = s.strip_suffix(unit)?.parse::<f64>().ok()?;
+            Some(value * factor)
+        })
 }
 
 /* -------------------------------------------------------------------------- */
@@ -113,6 +222,22 @@ impl From<&Timing> for JsonValue {
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "max_rss_bytes".into(),
+            match value.max_rss_bytes {
+                Some(x) => JsonValue::Number(x as f64),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "statistic".into(),
+            match value.statistic {
+                Some(x) => JsonValue::String(x.to_string()),
+                None => JsonValue::Null,
+            },
+        );
 
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
@@ -140,6 +265,7 @@ impl From<&Timing> for JsonValue {
 impl TryFrom<&JsonValue> for Timing {
     type Error = String;
 
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
         let json = value
             .get::<HashMap<String, JsonValue>>()
@@ -166,11 +292,35 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: older timings don't have this key, it's optional.
+        let max_rss_bytes = match json.get("max_rss_bytes") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .map(|x| *x as u64)
+                    .ok_or("Expected timing.max_rss_bytes to be null or a number.")?,
+            ),
+        };
+
+        // NOTE: older timings don't have this key either, their statistic is unknown.
+        let statistic = match json.get("statistic") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .and_then(|x| Statistic::from_str(x).ok())
+                    .ok_or("Expected timing.statistic to be null or a statistic.")?,
+            ),
+        };
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            max_rss_bytes,
+            statistic,
         })
     }
 }
@@ -191,25 +341,34 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    max_rss_bytes: None,
+                    statistic: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    max_rss_bytes: None,
+                    statistic: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    max_rss_bytes: None,
+                    statistic: None,
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{bench::Statistic, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
@@ -223,6 +382,28 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_peak_memory() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "max_rss_bytes": 4096 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].max_rss_bytes, Some(4096));
+
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].max_rss_bytes, None);
+        }
+
+        #[test]
+        fn handles_statistics() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "statistic": "median" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].statistic, Some(Statistic::Median));
+
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].statistic, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -282,6 +463,8 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    max_rss_bytes: None,
+                    statistic: None,
                 }],
             };
 
@@ -296,6 +479,8 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    max_rss_bytes: None,
+                    statistic: None,
                 }],
             };
 
@@ -310,6 +495,8 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    max_rss_bytes: None,
+                    statistic: None,
                 }],
             };
 
@@ -317,6 +504,95 @@ mod tests {
         }
     }
 
+    mod compare {
+        use std::collections::HashSet;
+
+        use crate::{
+            day,
+            template::bench::Statistic,
+            template::timings::{Timing, Timings},
+            template::Part,
+        };
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn compares_overlapping_parts() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![
+                    Timing {
+                        day: day!(2),
+                        part_1: Some("32ms".into()),
+                        part_2: Some("20.0ms".into()),
+                        total_nanos: 0_f64,
+                        max_rss_bytes: None,
+                        statistic: None,
+                    },
+                    Timing {
+                        day: day!(3),
+                        part_1: Some("1.0s".into()),
+                        part_2: None,
+                        total_nanos: 0_f64,
+                        max_rss_bytes: None,
+                        statistic: None,
+                    },
+                    Timing {
+                        day: day!(4),
+                        part_1: Some("40.0µs".into()),
+                        part_2: Some("1ms".into()),
+                        total_nanos: 0_f64,
+                        max_rss_bytes: None,
+                        statistic: None,
+                    },
+                ],
+            };
+
+            let comparisons = timings.compare(&other);
+            assert_eq!(comparisons.len(), 3);
+
+            assert_eq!(comparisons[0].day, day!(2));
+            assert_eq!(comparisons[0].part, Part::One);
+            assert_eq!(comparisons[0].is_regression(0.05), true);
+            assert_eq!(comparisons[0].is_regression(0.1), false);
+
+            assert_eq!(comparisons[1].part, Part::Two);
+            assert_eq!(comparisons[1].change(), -0.5);
+
+            assert_eq!(comparisons[2].day, day!(4));
+            assert_eq!(comparisons[2].new_nanos, 40_000_f64);
+            assert_eq!(comparisons[2].is_regression(0.0), false);
+        }
+
+        #[test]
+        fn handles_empty_timings() {
+            let timings = Timings::default();
+            assert_eq!(timings.compare(&get_mock_timings()).len(), 0);
+        }
+
+        #[test]
+        fn skips_other_statistics() {
+            let mut timings = get_mock_timings();
+            timings.data[0].statistic = Some(Statistic::Median);
+            timings.data[1].statistic = Some(Statistic::Mean);
+
+            let mut other = get_mock_timings();
+            for timing in &mut other.data {
+                timing.statistic = Some(Statistic::Mean);
+            }
+
+            let comparisons = timings.compare(&other);
+            assert_eq!(comparisons.len(), 3);
+            assert_eq!(comparisons.iter().any(|c| c.day == day!(1)), false);
+
+            let days = HashSet::from([day!(1), day!(2), day!(4)]);
+            assert_eq!(
+                timings.other_statistics(&days, Statistic::Mean),
+                vec![(day!(1), Statistic::Median)]
+            );
+        }
+    }
+
     mod merge {
         use crate::{
             day,
@@ -334,6 +610,8 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    max_rss_bytes: None,
+                    statistic: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -354,6 +632,8 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    max_rss_bytes: None,
+                    statistic: None,
                 }],
             };
             let merged = timings.merge(&other);
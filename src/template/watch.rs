@@ -0,0 +1,69 @@
+/// Minimal file watcher that polls modification times, using only std.
+use std::{
+    fs,
+    io::{stdout, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+const ANSI_CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+type Snapshot = Vec<(PathBuf, Option<SystemTime>)>;
+
+/// Calls `run`, then waits until one of the `files` changes and calls it again, forever.
+/// `files` is re-evaluated on every poll, so added or removed files are picked up as well.
+pub fn watch(files: impl Fn() -> Vec<PathBuf>, mut run: impl FnMut()) -> ! {
+    let mut last = snapshot(&files());
+
+    loop {
+        print!("{ANSI_CLEAR_SCREEN}");
+        let _ = stdout().flush();
+
+        run();
+        println!("\nWatching for changes...");
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let next = snapshot(&files());
+            if next != last {
+                last = next;
+                break;
+            }
+        }
+    }
+}
+
+/// Lists all files in `dir` and its subdirectories. Returns an empty list if `dir` can't be read.
+pub fn files_in(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .flat_map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                files_in(&path)
+            } else {
+                vec![path]
+            }
+        })
+        .collect()
+}
+
+fn snapshot(files: &[PathBuf]) -> Snapshot {
+    let mut snapshot: Snapshot = files
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect();
+
+    snapshot.sort_unstable();
+    snapshot
+}
@@ -0,0 +1,42 @@
+/// Selects the year that commands work on: the current one by default, or a year that was
+/// archived with `cargo archive`, e.g. with `cargo all --year 2024`.
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU16, Ordering};
+
+/// The selected archived year, `0` for the current one.
+static SELECTED: AtomicU16 = AtomicU16::new(0);
+
+/// Selects an archived year for the whole process, or the current year with `None`.
+pub fn select(year: Option<u16>) {
+    SELECTED.store(year.unwrap_or_default(), Ordering::Relaxed);
+}
+
+/// The selected archived year, `None` for the current year.
+pub fn selected() -> Option<u16> {
+    match SELECTED.load(Ordering::Relaxed) {
+        0 => None,
+        year => Some(year),
+    }
+}
+
+/// The folder with the solutions of an archived year, e.g. `src/archive/2024`.
+pub fn archive_dir(year: u16) -> PathBuf {
+    PathBuf::from("src").join("archive").join(year.to_string())
+}
+
+/// The folder with the inputs, examples, answers and timings of the selected year:
+/// `data` for the current year and e.g. `data/2024` for an archived one.
+pub fn data_dir() -> PathBuf {
+    match selected() {
+        Some(year) => PathBuf::from("data").join(year.to_string()),
+        None => PathBuf::from("data"),
+    }
+}
+
+/// The readme with the benchmark table of the selected year.
+pub fn readme_path() -> PathBuf {
+    match selected() {
+        Some(year) => archive_dir(year).join("README.md"),
+        None => PathBuf::from("README.md"),
+    }
+}